@@ -0,0 +1,84 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// Numbers found in the entries of `dir` named `<prefix><number><suffix>`
+fn numbered_entries(dir: &Path, prefix: &str, suffix: &str) -> Vec<usize> {
+    let mut numbers = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.unwrap().file_name().into_string().ok()?;
+            let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            number.parse::<usize>().ok()
+        })
+        .collect::<Vec<_>>();
+    numbers.sort();
+    numbers
+}
+
+// Generates the solver registry included by src/solutions/mod.rs from the
+// y<year>/dayNN.rs files found in src/solutions, so new days and years are
+// picked up without editing any source file.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let solutions_dir = Path::new(&manifest_dir).join("src/solutions");
+    println!("cargo:rerun-if-changed={}", solutions_dir.display());
+
+    let years = numbered_entries(&solutions_dir, "y", "");
+    let mut code = String::new();
+    let mut days_arms = String::new();
+    let mut solver_arms = String::new();
+    for year in years.iter() {
+        let year_dir = solutions_dir.join(format!("y{year}"));
+        let days = numbered_entries(&year_dir, "day", ".rs");
+
+        writeln!(code, "pub mod y{year} {{\n    use super::*;\n").unwrap();
+        for day in days.iter() {
+            let path = year_dir.join(format!("day{:02}.rs", day));
+            writeln!(code, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(code, "    pub mod day{:02};", day).unwrap();
+        }
+        writeln!(
+            code,
+            "\n    pub fn get_solver(day: usize) -> Option<&'static dyn DynSolver> {{"
+        )
+        .unwrap();
+        writeln!(code, "        match day {{").unwrap();
+        for day in days.iter() {
+            writeln!(code, "            {day} => Some(&day{:02}::Problem),", day).unwrap();
+        }
+        writeln!(code, "            _ => None,\n        }}\n    }}\n}}\n").unwrap();
+
+        let list = days.iter().map(|day| day.to_string()).collect::<Vec<_>>();
+        writeln!(days_arms, "        {year} => &[{}],", list.join(", ")).unwrap();
+        writeln!(solver_arms, "        {year} => y{year}::get_solver(day),").unwrap();
+    }
+
+    let list = years
+        .iter()
+        .map(|year| year.to_string())
+        .collect::<Vec<_>>();
+    writeln!(
+        code,
+        "pub const YEARS: &[usize] = &[{}];\n",
+        list.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "pub fn days(year: usize) -> &'static [usize] {{\n    match year {{\n{days_arms}        _ => &[],\n    }}\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "pub fn get_solver(year: usize, day: usize) -> Option<&'static dyn DynSolver> {{\n    match year {{\n{solver_arms}        _ => None,\n    }}\n}}"
+    )
+    .unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("registry.rs"), code).unwrap();
+}
@@ -0,0 +1,105 @@
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Allocs {
+    pub count: usize,
+    // Highest heap usage above what was allocated when the measure started
+    pub peak_bytes: usize,
+}
+
+impl Allocs {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} allocs, peak {}",
+            self.count,
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=9_999 => format!("{} B", bytes),
+        10_000..=9_999_999 => format!("{} kB", bytes / 1000),
+        _ => format!("{} MB", bytes / 1_000_000),
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::Allocs;
+
+    // Counters are per thread so parts running at the same time don't mix.
+    // Memory freed by another thread than the one allocating it can make
+    // `current` negative, which only lowers the peak of that other thread.
+    thread_local! {
+        static COUNT: Cell<usize> = const { Cell::new(0) };
+        static CURRENT: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<isize> = const { Cell::new(0) };
+    }
+
+    struct Counting;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    fn grow(size: isize) {
+        // try_with as the thread locals are gone while a thread shuts down
+        let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + size);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+        });
+    }
+
+    fn shrink(size: isize) {
+        let _ = CURRENT.try_with(|current| current.set(current.get() - size));
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            grow(layout.size() as isize);
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            grow(layout.size() as isize);
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            shrink(layout.size() as isize);
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            shrink(layout.size() as isize);
+            grow(new_size as isize);
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+        let count = COUNT.with(Cell::get);
+        let start = CURRENT.with(Cell::get);
+        PEAK.with(|peak| peak.set(start));
+        let res = f();
+        let allocs = Allocs {
+            count: COUNT.with(Cell::get) - count,
+            peak_bytes: (PEAK.with(Cell::get) - start) as usize,
+        };
+        (res, Some(allocs))
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+pub use counting::measure;
+
+// Without the alloc-stats feature the default allocator is used and nothing is counted
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    (f(), None)
+}
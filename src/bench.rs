@@ -0,0 +1,207 @@
+use std::hint::black_box;
+use std::panic::{self, catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::runner::panic_message;
+use crate::solutions::{self, Failure, Params};
+
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    // Err holds why a part failed, its times would be meaningless
+    pub parts: Vec<(usize, Result<Vec<Duration>, Failure>)>,
+}
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Stats {
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, mean {}, stddev {}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev)
+        )
+    }
+}
+
+// Stops at the first run that fails
+pub fn sample<T, E>(
+    warmup: usize,
+    runs: usize,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<Vec<Duration>, E> {
+    for _ in 0..warmup {
+        black_box(f()?);
+    }
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f()?);
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+// sample for a part, which fails rather than unwinds when the part panics
+pub fn sample_part<T>(
+    warmup: usize,
+    runs: usize,
+    f: impl FnMut() -> Result<T, String>,
+) -> Result<Vec<Duration>, Failure> {
+    catch_unwind(AssertUnwindSafe(|| sample(warmup, runs, f)))
+        .map_err(|payload| Failure::Panicked(panic_message(payload)))?
+        .map_err(Failure::Error)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2} μs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.3} s", nanos / 1e9)
+    }
+}
+
+pub fn bench_day(
+    year: usize,
+    day: usize,
+    parts: usize,
+    warmup: usize,
+    runs: usize,
+    params: &Params,
+) -> Result<Samples, String> {
+    if runs == 0 {
+        return Err(String::from("at least one run is needed"));
+    }
+    let solver = solutions::get_solver(year, day).ok_or("day not implemented")?;
+    let filename = solutions::input_file(year, day);
+    let contents = std::fs::read(&filename).map_err(|err| format!("{filename}: {err}"))?;
+    // Failed parts are reported with their stats, don't let the default hook spam stderr
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let samples = catch_unwind(AssertUnwindSafe(|| {
+        solver.bench(&contents, parts, warmup, runs, params)
+    }));
+    panic::set_hook(hook);
+    let samples = samples
+        .map_err(|payload| format!("panicked: {}", panic_message(payload)))?
+        .map_err(|err| format!("invalid input: {err}"))?;
+
+    println!("Day {:02}", day);
+    println!("Parse: {}", Stats::new(&samples.parse));
+    for (part, durations) in samples.parts.iter() {
+        match durations {
+            Ok(durations) => println!("Part {}: {}", part, Stats::new(durations)),
+            Err(failure) => println!("Part {}: {}", part, failure),
+        }
+    }
+    Ok(samples)
+}
+
+pub fn bench_all(year: usize, parts: usize, warmup: usize, runs: usize) {
+    let mut rows = vec![];
+    for &day in solutions::days(year) {
+        let samples = catch_unwind(AssertUnwindSafe(|| {
+            bench_day(year, day, parts, warmup, runs, &Params::new())
+        }))
+        .unwrap_or_else(|_| Err(String::from("panicked")));
+        if let Err(err) = &samples {
+            println!("Day {:02}: {}", day, err);
+        }
+        rows.push((day, samples));
+        println!();
+    }
+
+    println!(
+        "{:<5}{:>14}{:>14}{:>14}{:>14}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for (day, samples) in rows {
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(err) => {
+                println!("{:<5}{:>14}", format!("{:02}", day), err);
+                continue;
+            }
+        };
+        let parse = Stats::new(&samples.parse).median;
+        let medians = [1, 2].map(|part| {
+            samples
+                .parts
+                .iter()
+                .find(|(p, _)| *p == part)
+                .map(|(_, durations)| durations.as_ref().map(|d| Stats::new(d).median))
+        });
+        // Failed parts are left out of the total
+        let total = parse + medians.iter().flatten().flatten().sum::<Duration>();
+        let [first, second] = medians.map(|median| match median {
+            Some(Ok(median)) => format_duration(median),
+            Some(Err(_)) => String::from("failed"),
+            None => String::new(),
+        });
+        println!(
+            "{:<5}{:>14}{:>14}{:>14}{:>14}",
+            format!("{:02}", day),
+            format_duration(parse),
+            first,
+            second,
+            format_duration(total)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 10, 3, 2].map(Duration::from_micros);
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(4));
+        // The square root of a variance of 10 μs²
+        assert_eq!(stats.stddev, Duration::from_nanos(3162));
+    }
+
+    #[test]
+    fn failed_parts() {
+        let errored = sample_part(0, 3, || Err::<(), _>(String::from("no answer")));
+        assert!(matches!(errored, Err(Failure::Error(msg)) if msg == "no answer"));
+        let panicked = sample_part(0, 3, || -> Result<(), String> { panic!("no path") });
+        assert!(matches!(panicked, Err(Failure::Panicked(msg)) if msg == "no path"));
+        assert_eq!(sample_part(1, 3, || Ok(())).map(|d| d.len()).ok(), Some(3));
+    }
+}
@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Utc};
+
+use crate::solutions;
+
+pub const LAST_DAY: usize = 25;
+
+// Puzzles unlock at midnight US Eastern, which AoC keeps at UTC-5 all December
+fn release_offset() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+pub fn now() -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(&release_offset())
+}
+
+pub fn unlock_time(year: usize, day: usize) -> DateTime<FixedOffset> {
+    release_offset()
+        .with_ymd_and_hms(year as i32, 12, day as u32, 0, 0, 0)
+        .unwrap()
+}
+
+// Day unlocked today, days after the 25th of December fall back to the 25th
+pub fn current_day(year: usize, now: DateTime<FixedOffset>) -> Option<usize> {
+    if now.year() as usize == year && now.month() == 12 {
+        Some((now.day() as usize).min(LAST_DAY))
+    } else {
+        None
+    }
+}
+
+// Year and day of the first puzzle unlocking after `now`
+pub fn next_unlock(now: DateTime<FixedOffset>) -> (usize, usize) {
+    let year = now.year() as usize;
+    match current_day(year, now) {
+        Some(day) if day < LAST_DAY => (year, day + 1),
+        Some(_) => (year + 1, 1),
+        None => (year, 1),
+    }
+}
+
+pub fn get_year(year: Option<usize>) -> usize {
+    let now = now();
+    year.or_else(|| std::env::var("AOC_YEAR").ok()?.parse().ok())
+        .or_else(|| current_day(now.year() as usize, now).map(|_| now.year() as usize))
+        .or_else(|| solutions::YEARS.last().copied())
+        .unwrap_or(now.year() as usize)
+}
+
+// Defaults to the day unlocked today, which only exists in December
+pub fn get_day(year: usize, day: Option<usize>) -> Result<usize, String> {
+    match day {
+        Some(day) if (1..=LAST_DAY).contains(&day) => Ok(day),
+        Some(day) => Err(format!("day {day} is not between 1 and {LAST_DAY}")),
+        None => {
+            current_day(year, now()).ok_or(format!("no puzzle of {year} unlocks today, pass a day"))
+        }
+    }
+}
+
+// A single day or a range such as 5..12, 5..=12 or 20..
+#[derive(Clone, Debug)]
+pub struct DaySelection(pub Vec<usize>);
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Only the ends of a range may be left out
+        let parse = |v: &str, default: Option<usize>| -> Result<usize, String> {
+            if v.is_empty() {
+                return default.ok_or_else(|| String::from("no day given"));
+            }
+            match v.parse() {
+                Ok(day @ 1..=LAST_DAY) => Ok(day),
+                Ok(day) => Err(format!("day {day} is not between 1 and {LAST_DAY}")),
+                Err(_) => Err(format!("invalid day `{v}`")),
+            }
+        };
+
+        let days = if let Some((from, to)) = s.split_once("..=") {
+            (parse(from, Some(1))?..=parse(to, Some(LAST_DAY))?).collect()
+        } else if let Some((from, to)) = s.split_once("..") {
+            // An exclusive end may be one past the last day
+            let to = match to {
+                "26" => LAST_DAY + 1,
+                to => parse(to, Some(LAST_DAY + 1))?,
+            };
+            (parse(from, Some(1))?..to).collect()
+        } else {
+            vec![parse(s, None)?]
+        };
+        if days.is_empty() {
+            return Err(format!("`{s}` selects no days"));
+        }
+        Ok(DaySelection(days))
+    }
+}
+
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.num_seconds().max(0);
+    format!(
+        "{}h {:02}m {:02}s",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(s: &str) -> Result<Vec<usize>, String> {
+        s.parse::<DaySelection>().map(|selection| selection.0)
+    }
+
+    #[test]
+    fn day_selection() {
+        assert_eq!(days("7"), Ok(vec![7]));
+        assert_eq!(days("5..12"), Ok((5..12).collect()));
+        assert_eq!(days("5..=12"), Ok((5..=12).collect()));
+        assert_eq!(days("..=3"), Ok(vec![1, 2, 3]));
+        assert_eq!(days("20.."), Ok((20..=25).collect()));
+        assert_eq!(days("20..26"), Ok((20..=25).collect()));
+        assert_eq!(days("25..=25"), Ok(vec![25]));
+    }
+
+    #[test]
+    fn invalid_day_selection() {
+        assert!(days("0").is_err());
+        assert!(days("26").is_err());
+        assert!(days("0..3").is_err());
+        assert!(days("20..=26").is_err());
+        assert!(days("20..27").is_err());
+        assert!(days("5..3").is_err());
+        assert!(days("5..5").is_err());
+        assert!(days("").is_err());
+        assert!(days("five").is_err());
+        assert!(days("1...3").is_err());
+    }
+
+    fn at(year: i32, month: u32, day: u32, hour: u32) -> DateTime<FixedOffset> {
+        release_offset()
+            .with_ymd_and_hms(year, month, day, hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn next_unlock_in_december() {
+        assert_eq!(next_unlock(at(2024, 12, 1, 0)), (2024, 2));
+        assert_eq!(next_unlock(at(2024, 12, 24, 23)), (2024, 25));
+        assert_eq!(next_unlock(at(2024, 12, 25, 0)), (2025, 1));
+        assert_eq!(next_unlock(at(2024, 12, 31, 12)), (2025, 1));
+    }
+
+    #[test]
+    fn next_unlock_outside_december() {
+        assert_eq!(next_unlock(at(2025, 1, 1, 0)), (2025, 1));
+        assert_eq!(next_unlock(at(2025, 11, 30, 23)), (2025, 1));
+    }
+
+    #[test]
+    fn unlock_is_midnight_eastern() {
+        let unlock = unlock_time(2024, 3).with_timezone(&Utc);
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 3, 5, 0, 0).unwrap());
+    }
+}
@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::time::Duration;
+
+use clap::Args;
+use reqwest::StatusCode;
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+// Its modification time is the time of the last request, shared by all invocations
+const LAST_REQUEST_FILE: &str = ".last_request";
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Args, Debug)]
+pub struct Server {
+    /// Defaults to the AOC_URL env variable, then to https://adventofcode.com
+    #[arg(long)]
+    url: Option<String>,
+}
+
+impl Server {
+    pub fn base_url(&self, year: usize) -> String {
+        let url = self
+            .url
+            .clone()
+            .or_else(|| std::env::var("AOC_URL").ok())
+            .unwrap_or_else(|| DEFAULT_URL.to_string());
+        format!("{}/{}", url.trim_end_matches('/'), year)
+    }
+}
+
+// AoC asks automated tools to identify themselves, AOC_CONTACT is added when set
+fn user_agent() -> String {
+    let agent = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    match std::env::var("AOC_CONTACT") {
+        Ok(contact) => format!("{agent} ({contact})"),
+        Err(_) => agent.to_string(),
+    }
+}
+
+// Client sending the session cookie from the TOKEN env variable
+pub fn build() -> Result<reqwest::Client, Box<dyn Error>> {
+    let mut headers = reqwest::header::HeaderMap::new();
+
+    let token = std::env::var("TOKEN").map_err(|_| "Missing TOKEN env variable")?;
+    let cookie = format!("session={}", token);
+    headers.insert("cookie", reqwest::header::HeaderValue::from_str(&cookie)?);
+
+    let client = reqwest::Client::builder()
+        .default_headers(headers)
+        .user_agent(user_agent())
+        .build()?;
+    Ok(client)
+}
+
+// Waits until MIN_INTERVAL has passed since the previous request
+pub async fn throttle() -> std::io::Result<()> {
+    let last = std::fs::metadata(LAST_REQUEST_FILE).and_then(|meta| meta.modified());
+    if let Some(elapsed) = last.ok().and_then(|last| last.elapsed().ok()) {
+        if elapsed < MIN_INTERVAL {
+            tokio::time::sleep(MIN_INTERVAL - elapsed).await;
+        }
+    }
+    std::fs::write(LAST_REQUEST_FILE, "")
+}
+
+// Explanation of a failed request, None when it succeeded
+fn status_error(url: &str, status: StatusCode) -> Option<String> {
+    match status {
+        StatusCode::NOT_FOUND => Some(format!("{url}: not found, is the puzzle unlocked yet?")),
+        StatusCode::BAD_REQUEST => Some(format!(
+            "{url}: bad request, is the TOKEN session cookie valid?"
+        )),
+        status if !status.is_success() => Some(format!("{url}: {status}")),
+        _ => None,
+    }
+}
+
+pub async fn body(response: reqwest::Response) -> Result<String, Box<dyn Error>> {
+    match status_error(response.url().as_str(), response.status()) {
+        Some(err) => Err(err.into()),
+        None => Ok(response.text().await?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    #[test]
+    fn status_errors() {
+        let url = "https://adventofcode.com/2024/day/1/input";
+        assert_eq!(
+            status_error(url, StatusCode::NOT_FOUND).unwrap(),
+            format!("{url}: not found, is the puzzle unlocked yet?")
+        );
+        assert_eq!(
+            status_error(url, StatusCode::BAD_REQUEST).unwrap(),
+            format!("{url}: bad request, is the TOKEN session cookie valid?")
+        );
+        assert_eq!(
+            status_error(url, StatusCode::INTERNAL_SERVER_ERROR).unwrap(),
+            format!("{url}: 500 Internal Server Error")
+        );
+        assert_eq!(status_error(url, StatusCode::OK), None);
+    }
+
+    // Answers a single request with `status` and `content`
+    fn serve(status: &'static str, content: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/input", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{content}",
+                content.len()
+            )
+            .unwrap();
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn bodies() {
+        let url = serve("200 OK", "1 2\n3 4\n");
+        let response = reqwest::get(&url).await.unwrap();
+        assert_eq!(body(response).await.unwrap(), "1 2\n3 4\n");
+
+        let url = serve("404 Not Found", "");
+        let response = reqwest::get(&url).await.unwrap();
+        assert_eq!(
+            body(response).await.unwrap_err().to_string(),
+            format!("{url}: not found, is the puzzle unlocked yet?")
+        );
+    }
+}
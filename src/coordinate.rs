@@ -1,6 +1,6 @@
 #![allow(unused)]
 
-use std::{cmp::Ordering, fmt::Display};
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
 use auto_ops::{impl_op, impl_op_ex};
 
@@ -63,6 +63,23 @@ impl PartialOrd for Coordinate {
     }
 }
 
+// Parsed from `a,b`
+impl FromStr for Coordinate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s
+            .split_once(',')
+            .ok_or_else(|| format!("expected `a,b`, found `{s}`"))?;
+        let parse = |v: &str| {
+            v.trim()
+                .parse()
+                .map_err(|err| format!("invalid coordinate `{s}`: {err}"))
+        };
+        Ok(Coordinate(parse(a)?, parse(b)?))
+    }
+}
+
 pub fn get_coordinates_from<T>(input: &Vec<Vec<T>>) -> impl Iterator<Item = (Coordinate, &T)> {
     input.iter().enumerate().flat_map(|(r, v)| {
         v.iter()
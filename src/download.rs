@@ -0,0 +1,224 @@
+use std::error::Error;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use crate::calendar;
+use crate::client::{self, Server};
+use crate::puzzle;
+use crate::solutions;
+
+// Starting points for a new day, named after the shape of input they parse
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Template {
+    /// Characters into Vec<Vec<char>>
+    Grid,
+    /// Lines as they are
+    Lines,
+    /// Lines grouped by the blank lines between them
+    Sections,
+    /// Whitespace separated integers of each line
+    Numbers,
+    /// A record per line captured by a regex
+    Regex,
+}
+
+impl Template {
+    fn name(&self) -> String {
+        self.to_possible_value().unwrap().get_name().to_string()
+    }
+
+    fn file(&self) -> String {
+        format!("src/solutions/templates/{}.rs", self.name())
+    }
+
+    // Guesses the template from what the input looks like
+    pub fn infer(input: &str) -> Template {
+        let lines = input.lines().collect::<Vec<_>>();
+        let is_number = |v: &str| v.strip_prefix('-').unwrap_or(v).parse::<u64>().is_ok();
+        if lines.iter().any(|line| line.is_empty()) {
+            Template::Sections
+        } else if lines
+            .iter()
+            .all(|line| line.split_whitespace().all(is_number))
+        {
+            Template::Numbers
+        } else if lines.len() > 1
+            && lines
+                .iter()
+                .all(|line| line.len() == lines[0].len() && !line.contains(char::is_whitespace))
+        {
+            Template::Grid
+        } else if lines
+            .iter()
+            .all(|line| line.contains(|c: char| c.is_ascii_digit()))
+        {
+            Template::Regex
+        } else {
+            Template::Lines
+        }
+    }
+}
+
+// Extra wait after the unlock time, in case our clock is ahead of the server's
+const UNLOCK_MARGIN: Duration = Duration::from_secs(2);
+
+// Counts down on a single line until the puzzle unlocks
+pub async fn wait_for_unlock(year: usize, day: usize) -> std::io::Result<()> {
+    let unlock = calendar::unlock_time(year, day);
+    loop {
+        let left = unlock - calendar::now();
+        if left <= chrono::Duration::zero() {
+            break;
+        }
+        print!(
+            "\r{} day {:02} unlocks in {} ",
+            year,
+            day,
+            calendar::format_wait(left)
+        );
+        std::io::stdout().flush()?;
+        let step = left
+            .to_std()
+            .unwrap_or_default()
+            .min(Duration::from_secs(1));
+        tokio::time::sleep(step).await;
+    }
+    println!();
+    tokio::time::sleep(UNLOCK_MARGIN).await;
+    Ok(())
+}
+
+// Inputs and puzzle descriptions already on disk are only fetched again with `force`
+pub async fn download(
+    year: usize,
+    day: usize,
+    force: bool,
+    template: Option<Template>,
+    server: &Server,
+) -> Result<(), Box<dyn Error>> {
+    let client = client::build()?;
+
+    let input = solutions::input_file(year, day);
+    if !force && Path::new(&input).exists() {
+        println!("{input} already exists, use --force to download it again");
+    } else {
+        client::throttle().await?;
+        let response = client
+            .get(format!("{}/day/{day}/input", server.base_url(year)))
+            .send()
+            .await?;
+        let body = client::body(response).await?;
+
+        if body.contains("Please don't repeatedly request this endpoint") {
+            println!("Day {:02} not yet ready", day);
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(format!("inputs/{year}"))?;
+        std::fs::write(&input, body)?;
+    }
+
+    let mut answers = (None, None);
+    let description = puzzle::puzzle_file(year, day);
+    if !force && Path::new(&description).exists() {
+        println!("{description} already exists, use --force to download it again");
+    } else {
+        client::throttle().await?;
+        let response = client
+            .get(format!("{}/day/{day}", server.base_url(year)))
+            .send()
+            .await?;
+        let html = client::body(response).await?;
+        let articles = puzzle::articles(&html);
+        if articles.is_empty() {
+            return Err(format!("no puzzle description found for day {day}").into());
+        }
+
+        std::fs::create_dir_all(format!("puzzles/{year}"))?;
+        std::fs::write(&description, puzzle::to_markdown(&articles))?;
+
+        // Checked in examples may have been edited by hand, they are never overwritten
+        let example = example_file(year, day);
+        if !Path::new(&example).exists() {
+            if let Some(contents) = puzzle::example_input(articles[0]) {
+                std::fs::create_dir_all(format!("inputs/{year}/examples"))?;
+                std::fs::write(&example, contents)?;
+            }
+        }
+        answers = (
+            puzzle::example_answer(articles[0]),
+            articles
+                .get(1)
+                .and_then(|article| puzzle::example_answer(article)),
+        );
+    }
+
+    let template = match template {
+        Some(template) => template,
+        None => Template::infer(&std::fs::read_to_string(&input)?),
+    };
+    copy_template(year, day, template, answers)?;
+
+    Ok(())
+}
+
+fn example_file(year: usize, day: usize) -> String {
+    format!("inputs/{year}/examples/{:02}", day)
+}
+
+// An existing solution is never overwritten. The example answers are guesses
+// taken from the puzzle text and should be checked.
+fn copy_template(
+    year: usize,
+    day: usize,
+    template: Template,
+    (part1, part2): (Option<String>, Option<String>),
+) -> Result<(), std::io::Error> {
+    let path = format!("src/solutions/y{year}/day{:02}.rs", day);
+    if Path::new(&path).exists() {
+        return Ok(());
+    }
+
+    let file = example_file(year, day);
+    let examples = if Path::new(&file).exists() {
+        let answer =
+            |answer: Option<String>| answer.map_or("None".into(), |v| format!("Some({v:?})"));
+        format!(
+            "Example {{\n            file: {file:?},\n            part1: {},\n            part2: {},\n            params: &[],\n        }}",
+            answer(part1),
+            answer(part2)
+        )
+    } else {
+        String::new()
+    };
+
+    println!("Creating {path} from the {} template", template.name());
+    let template = std::fs::read_to_string(template.file())?;
+    std::fs::create_dir_all(format!("src/solutions/y{year}"))?;
+    std::fs::write(path, template.replace("{{examples}}", &examples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_templates() {
+        assert_eq!(Template::infer("....#\n.#..^\n#...."), Template::Grid);
+        assert_eq!(
+            Template::infer("47|53\n97|13\n\n75,47,61"),
+            Template::Sections
+        );
+        assert_eq!(Template::infer("3   4\n4   3\n-2   5"), Template::Numbers);
+        assert_eq!(
+            Template::infer("p=0,4 v=3,-3\np=6,3 v=-1,-3"),
+            Template::Regex
+        );
+        assert_eq!(Template::infer("r, wr, b\nbwurrg"), Template::Lines);
+        // A single line isn't enough to tell a grid
+        assert_eq!(Template::infer("..#.#"), Template::Lines);
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::bench::format_duration;
+use crate::solutions::PartResult;
+
+const HISTORY_FILE: &str = "history.csv";
+
+pub struct Record {
+    pub commit: String,
+    pub profile: String,
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub elapsed: Duration,
+}
+
+// Timings from debug and release builds are not comparable
+fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return String::from("unknown");
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{commit}-dirty"),
+        _ => commit,
+    }
+}
+
+pub fn record(year: usize, day: usize, results: &[PartResult]) -> Result<(), std::io::Error> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE)?;
+    let commit = current_commit();
+    let timestamp = chrono::Utc::now().to_rfc3339();
+
+    for result in results.iter().filter(|r| r.answer.is_ok()) {
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{}",
+            commit,
+            profile(),
+            timestamp,
+            year,
+            day,
+            result.part,
+            result.elapsed.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+pub fn read_history() -> Vec<Record> {
+    let Ok(contents) = std::fs::read_to_string(HISTORY_FILE) else {
+        return vec![];
+    };
+    parse_history(&contents)
+}
+
+// Lines that aren't a record are skipped
+fn parse_history(contents: &str) -> Vec<Record> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let fields = line.split(',').collect::<Vec<_>>();
+            let [commit, profile, _timestamp, year, day, part, elapsed] = fields[..] else {
+                return None;
+            };
+            Some(Record {
+                commit: commit.to_string(),
+                profile: profile.to_string(),
+                year: year.parse().ok()?,
+                day: day.parse().ok()?,
+                part: part.parse().ok()?,
+                elapsed: Duration::from_nanos(elapsed.parse().ok()?),
+            })
+        })
+        .collect()
+}
+
+// Fastest recorded time for each year/day/part of a commit, single runs are too
+// noisy to compare otherwise.
+fn best_times(history: &[Record], commit: &str) -> HashMap<(usize, usize, usize), Duration> {
+    let mut result: HashMap<(usize, usize, usize), Duration> = HashMap::new();
+    for record in history.iter().filter(|r| r.commit == commit) {
+        let entry = result
+            .entry((record.year, record.day, record.part))
+            .or_insert(record.elapsed);
+        *entry = (*entry).min(record.elapsed);
+    }
+    result
+}
+
+// Percentage by which a part got slower, None when there is no baseline time to
+// compare against
+fn change(now: Duration, before: Duration) -> Option<f64> {
+    if before.is_zero() {
+        return None;
+    }
+    Some((now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0)
+}
+
+pub fn compare(baseline: Option<String>, threshold: f64) -> Result<bool, String> {
+    let history = read_history()
+        .into_iter()
+        .filter(|r| r.profile == profile())
+        .collect::<Vec<_>>();
+    compare_commits(&history, &current_commit(), baseline, threshold)
+}
+
+// Prints the change of every part of `current` and whether none is slower than
+// `threshold` percent
+fn compare_commits(
+    history: &[Record],
+    current: &str,
+    baseline: Option<String>,
+    threshold: f64,
+) -> Result<bool, String> {
+    let baseline = baseline
+        .or_else(|| {
+            history
+                .iter()
+                .rev()
+                .find(|r| r.commit != current)
+                .map(|r| r.commit.clone())
+        })
+        .ok_or("no baseline found in history")?;
+
+    let current_times = best_times(history, current);
+    if current_times.is_empty() {
+        return Err(format!("no {} timings recorded for {}", profile(), current));
+    }
+    let baseline_times = best_times(history, &baseline);
+
+    println!("Comparing {} against baseline {}", current, baseline);
+    let mut keys = current_times.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let mut regressions = 0;
+    for key in keys {
+        let (year, day, part) = key;
+        let now = current_times[key];
+        let Some(before) = baseline_times.get(key) else {
+            println!(
+                "{} day {:02} part {}: {} (no baseline)",
+                year,
+                day,
+                part,
+                format_duration(now)
+            );
+            continue;
+        };
+        let change = change(now, *before);
+        let slower = change.is_some_and(|change| change > threshold);
+        if slower {
+            regressions += 1;
+        }
+        println!(
+            "{} day {:02} part {}: {} -> {} ({}){}",
+            year,
+            day,
+            part,
+            format_duration(*before),
+            format_duration(now),
+            change.map_or(String::from("no baseline time"), |change| format!(
+                "{change:+.1}%"
+            )),
+            if slower { " SLOWER" } else { "" }
+        );
+    }
+
+    println!("{regressions} parts slower than {threshold}%");
+    Ok(regressions == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: usize, part: usize, micros: u64) -> Record {
+        Record {
+            commit: commit.to_string(),
+            profile: String::from("release"),
+            year: 2024,
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn parses_history() {
+        let history = parse_history(
+            "abc1234,release,2024-12-01T06:00:00+00:00,2024,1,2,1500\n\
+             not,a,record\n\
+             abc1234,debug,2024-12-01T06:00:00+00:00,2024,x,1,1500\n",
+        );
+        let [record] = &history[..] else {
+            panic!("expected a single record");
+        };
+        assert_eq!(record.commit, "abc1234");
+        assert_eq!(record.profile, "release");
+        assert_eq!((record.year, record.day, record.part), (2024, 1, 2));
+        assert_eq!(record.elapsed, Duration::from_nanos(1500));
+    }
+
+    #[test]
+    fn best_times_of_a_commit() {
+        let history = [
+            record("old", 1, 1, 10),
+            record("new", 1, 1, 30),
+            record("new", 1, 1, 20),
+            record("new", 1, 2, 40),
+        ];
+        let best = best_times(&history, "new");
+        assert_eq!(best.len(), 2);
+        assert_eq!(best[&(2024, 1, 1)], Duration::from_micros(20));
+        assert_eq!(best[&(2024, 1, 2)], Duration::from_micros(40));
+        assert!(best_times(&history, "other").is_empty());
+    }
+
+    #[test]
+    fn changes() {
+        let micros = Duration::from_micros;
+        let close = |change: Option<f64>, expected: f64| (change.unwrap() - expected).abs() < 1e-9;
+        assert!(close(change(micros(150), micros(100)), 50.0));
+        assert!(close(change(micros(50), micros(100)), -50.0));
+        assert_eq!(change(micros(50), Duration::ZERO), None);
+    }
+
+    #[test]
+    fn compares_commits() {
+        let history = [
+            record("old", 1, 1, 100),
+            record("old", 1, 2, 100),
+            record("new", 1, 1, 105),
+            record("new", 1, 2, 200),
+            record("new", 2, 1, 100),
+        ];
+        // Part 2 of day 1 doubled, day 2 has no baseline
+        assert_eq!(compare_commits(&history, "new", None, 10.0), Ok(false));
+        assert_eq!(compare_commits(&history, "new", None, 100.0), Ok(true));
+        assert_eq!(
+            compare_commits(&history, "new", Some(String::from("new")), 10.0),
+            Ok(true)
+        );
+        // A zero baseline can't be slower than
+        let history = [record("old", 1, 1, 0), record("new", 1, 1, 100)];
+        assert_eq!(compare_commits(&history, "new", None, 10.0), Ok(true));
+        assert!(compare_commits(&history, "other", None, 10.0).is_err());
+        assert!(compare_commits(&[], "new", None, 10.0).is_err());
+    }
+}
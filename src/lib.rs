@@ -0,0 +1,20 @@
+// Solutions and the tooling around them. The aoc-2024 binary in main.rs is a
+// command line frontend over this crate.
+pub mod alloc;
+pub mod bench;
+pub mod calendar;
+pub mod client;
+pub mod coordinate;
+pub mod download;
+pub mod history;
+pub mod many_to_many;
+pub mod output;
+pub mod puzzle;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod solutions;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+extern crate lazy_static;
@@ -1,109 +1,283 @@
-use chrono::Datelike;
-use clap::{Args, Parser};
-use std::io::Read;
-
-mod coordinate;
-mod many_to_many;
-mod solutions;
-extern crate lazy_static;
+use aoc_2024::output::Format;
+use aoc_2024::solutions::params::{self, ParamValue};
+use aoc_2024::{
+    bench, calendar, client, download, history, repl, runner, solutions, submit, verify, watch,
+};
+use clap::builder::RangedU64ValueParser;
+use clap::{Args, Parser, Subcommand};
 
 #[derive(Parser, Debug)]
+struct Cli {
+    /// Defaults to the AOC_YEAR env variable, then to the current year in December,
+    /// then to the latest year with solutions
+    #[arg(long, global = true)]
+    year: Option<usize>,
+    /// Debug output of the solutions as `<day>:<level>`, `all` for every day and
+    /// info, debug or trace as level. Can be repeated.
+    #[arg(long, global = true)]
+    trace: Vec<solutions::trace::Target>,
+    /// File to append the debug output to instead of stderr
+    #[arg(long, global = true)]
+    trace_file: Option<String>,
+    #[command(subcommand)]
+    command: CLIArgs,
+}
+
+#[derive(Subcommand, Debug)]
 enum CLIArgs {
+    /// Solve days and print their answers and timings
     Run(RunCommand),
+    /// Fetch a day's input and puzzle and set up its solution
     Download(DownloadCommand),
+    /// Check the answers against the ones stored in answers/
+    Verify(VerifyCommand),
+    /// Time the parsing and parts of days over several runs
+    Bench(BenchCommand),
+    /// Compare the recorded timings against an earlier commit
+    Compare(CompareCommand),
+    /// List the days with solutions and their parameters
+    List,
+    /// Send a part's answer to the server
+    Submit(SubmitCommand),
+    /// Load a day's input and solve it interactively
+    Repl(ReplCommand),
 }
 
 #[derive(Args, Debug)]
 struct RunCommand {
-    #[arg(default_value_t = 3)]
+    /// A single day or a range such as 5..12, 5..=12 or 20.., defaults to today's puzzle
+    day: Option<calendar::DaySelection>,
+    /// 1 or 2 for a single part, 3 for both
+    #[arg(long, short, default_value_t = 3)]
     part: usize,
-    day: Option<usize>,
+    /// Run every day with a solution
+    #[arg(long)]
+    all: bool,
+    /// Run the examples of the day instead of its input
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Input file to use instead of inputs/YYYY/DD, `-` to read from stdin
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Number of days to run at the same time
+    #[arg(long, short, default_value_t = 1)]
+    jobs: usize,
+    /// Run part 1 and part 2 of each day on separate threads
+    #[arg(long)]
+    parallel_parts: bool,
+    /// Seconds a part may run before it is reported as timed out, 0 for no limit
+    #[arg(long, default_value_t = 60)]
+    timeout: u64,
+    /// Rebuild and run the day and its examples again whenever its files change
+    #[arg(long, conflicts_with_all = ["all", "example", "input", "format"])]
+    watch: bool,
+    /// Overrides a parameter declared by the day as `name=value`, can be repeated
+    #[arg(long = "param")]
+    params: Vec<ParamValue>,
+    /// Also write an HTML report of the run, with the answers, timings and visualizations
+    #[arg(long, conflicts_with_all = ["example", "input", "watch"])]
+    report: Option<String>,
 }
 
 #[derive(Args, Debug)]
 struct DownloadCommand {
+    /// Defaults to today's puzzle
     day: Option<usize>,
+    /// Download the input and puzzle again even if they already exist
+    #[arg(long)]
+    force: bool,
+    /// Sleep until the day unlocks first, the next one to unlock if no day is given
+    #[arg(long)]
+    wait: bool,
+    /// Inferred from the input when not given
+    #[arg(long, value_enum)]
+    template: Option<download::Template>,
+    #[command(flatten)]
+    server: client::Server,
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    dotenv::dotenv().ok();
-
-    let args = CLIArgs::parse();
-
-    match args {
-        CLIArgs::Run(c) => {
-            let day = get_day(c.day);
-            solutions::solve(day, c.part);
-
-            Ok(())
-        }
-        CLIArgs::Download(c) => download(get_day(c.day)).await,
-    }
+#[derive(Args, Debug)]
+struct VerifyCommand {
+    /// Defaults to every day with a solution
+    day: Option<usize>,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Seconds a part may run before it is reported as timed out, 0 for no limit
+    #[arg(long, default_value_t = 60)]
+    timeout: u64,
 }
 
-async fn download(selected_day: usize) -> Result<(), Box<dyn std::error::Error>> {
-    let mut headers = reqwest::header::HeaderMap::new();
-
-    let token = std::env::var("TOKEN");
-    if let Err(_) = token {
-        panic!("Missing TOKEN env variable");
-    }
-    let cookie = format!("session={}", token?);
-    headers.insert("cookie", reqwest::header::HeaderValue::from_str(&cookie)?);
-
-    let client = reqwest::Client::builder()
-        .default_headers(headers)
-        .build()?;
-
-    let body = client
-        .get(format!(
-            "https://adventofcode.com/2024/day/{selected_day}/input"
-        ))
-        .send()
-        .await?
-        .text()
-        .await?;
-
-    if body.contains("Please don't repeatedly request this endpoint") {
-        println!("Day {:02} not yet ready", selected_day);
-        return Ok(());
-    }
-
-    std::fs::write(format!("inputs/{:02}", selected_day), body)?;
-
-    copy_template(selected_day)?;
+#[derive(Args, Debug)]
+struct BenchCommand {
+    /// Defaults to today's puzzle
+    day: Option<usize>,
+    /// 1 or 2 for a single part, 3 for both
+    #[arg(long, short, default_value_t = 3)]
+    part: usize,
+    /// Bench every day with a solution
+    #[arg(long)]
+    all: bool,
+    /// Timed runs of each part, at least 1
+    #[arg(
+        long,
+        default_value_t = 10,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    runs: usize,
+    /// Untimed runs of each part before the timed ones
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+    /// Overrides a parameter declared by the day as `name=value`, can be repeated
+    #[arg(long = "param", conflicts_with = "all")]
+    params: Vec<ParamValue>,
+}
 
-    append_day(selected_day)?;
+#[derive(Args, Debug)]
+struct CompareCommand {
+    /// Commit to compare against, defaults to the last one recorded before the current one
+    #[arg(long)]
+    baseline: Option<String>,
+    /// Percentage a part can get slower before being flagged
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
 
-    Ok(())
+#[derive(Args, Debug)]
+struct SubmitCommand {
+    day: usize,
+    /// 1 or 2
+    part: usize,
+    #[command(flatten)]
+    server: client::Server,
 }
 
-fn copy_template(selected_day: usize) -> Result<u64, std::io::Error> {
-    return std::fs::copy(
-        "src/solutions/template.rs",
-        format!("src/solutions/day{:02}.rs", selected_day),
-    );
+#[derive(Args, Debug)]
+struct ReplCommand {
+    /// Defaults to today's puzzle
+    day: Option<usize>,
+    /// Input file to use instead of inputs/YYYY/DD
+    #[arg(long)]
+    input: Option<String>,
+    /// Starting values of the parameters as `name=value`, they can be changed in the repl
+    #[arg(long = "param")]
+    params: Vec<ParamValue>,
 }
 
-fn append_day(selected_day: usize) -> Result<(), std::io::Error> {
-    let mut file = std::fs::File::open("src/solutions/mod.rs")?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv::dotenv().ok();
+
+    let cli = Cli::parse();
+    let year = calendar::get_year(cli.year);
+    solutions::trace::init(year, cli.trace.clone(), cli.trace_file.as_deref())?;
 
-    let new_mod = format!("mod day{:02};", selected_day);
-    contents = contents.replace("mod solver;", &format!("{new_mod}\nmod solver;"));
+    match cli.command {
+        CLIArgs::Run(c) => {
+            let days = if c.all {
+                solutions::days(year).to_vec()
+            } else {
+                match c.day {
+                    Some(days) => days.0,
+                    None => vec![calendar::get_day(year, None)?],
+                }
+            };
 
-    let new_match = format!(
-        "{selected_day} => day{:02}::Problem.solve(filename, parts),",
-        selected_day
-    );
-    contents = contents.replace("_ => panic!", &format!("{new_match}\n        _ => panic!"));
+            let params = params::resolve(year, &days, &c.params)?;
+            if c.watch {
+                let [day] = days[..] else {
+                    return Err("--watch can only be used with a single day".into());
+                };
+                return watch::watch(
+                    year,
+                    day,
+                    c.part,
+                    c.timeout,
+                    &c.params,
+                    &cli.trace,
+                    cli.trace_file.as_deref(),
+                )
+                .await;
+            }
 
-    return std::fs::write("src/solutions/mod.rs", contents);
+            let options = runner::RunOptions {
+                parts: c.part,
+                example: c.example,
+                input: c.input,
+                format: c.format,
+                jobs: c.jobs,
+                parallel: c.parallel_parts,
+                timeout: get_timeout(c.timeout),
+                params,
+                report: c.report,
+            };
+            runner::run(year, &days, &options).await
+        }
+        CLIArgs::Download(c) => {
+            let (year, day) = match (c.wait, c.day) {
+                (true, None) => calendar::next_unlock(calendar::now()),
+                _ => (year, calendar::get_day(year, c.day)?),
+            };
+            if c.wait {
+                download::wait_for_unlock(year, day).await?;
+            }
+            download::download(year, day, c.force, c.template, &c.server).await
+        }
+        CLIArgs::Verify(c) => {
+            let days = match c.day {
+                Some(day) => {
+                    let day = calendar::get_day(year, Some(day))?;
+                    solutions::get_solver(year, day).ok_or("day not implemented")?;
+                    vec![day]
+                }
+                None => solutions::days(year).to_vec(),
+            };
+            if verify::verify(year, days, c.format, get_timeout(c.timeout)) {
+                Ok(())
+            } else {
+                Err("verification failed".into())
+            }
+        }
+        CLIArgs::Bench(c) => {
+            if c.all {
+                bench::bench_all(year, c.part, c.warmup, c.runs);
+            } else {
+                let day = calendar::get_day(year, c.day)?;
+                let params = params::resolve(year, &[day], &c.params)?;
+                bench::bench_day(year, day, c.part, c.warmup, c.runs, &params)?;
+            }
+
+            Ok(())
+        }
+        CLIArgs::Compare(c) => {
+            if history::compare(c.baseline, c.threshold)? {
+                Ok(())
+            } else {
+                Err("performance regression".into())
+            }
+        }
+        CLIArgs::Submit(c) => match submit::submit(year, c.day, c.part, &c.server).await? {
+            submit::Outcome::Correct => Ok(()),
+            _ => Err("answer not accepted".into()),
+        },
+        CLIArgs::Repl(c) => {
+            let day = calendar::get_day(year, c.day)?;
+            let params = params::resolve(year, &[day], &c.params)?;
+            let input = c.input.unwrap_or_else(|| solutions::input_file(year, day));
+            repl::repl(year, day, &input, params)
+        }
+        CLIArgs::List => {
+            // Every year unless one is asked for
+            let years = cli
+                .year
+                .map_or(solutions::YEARS.to_vec(), |year| vec![year]);
+            solutions::list(&years);
+            Ok(())
+        }
+    }
 }
 
-fn get_day(day: Option<usize>) -> usize {
-    let default_day = chrono::Utc::now().day() as usize;
-    day.unwrap_or(default_day)
+fn get_timeout(seconds: u64) -> Option<std::time::Duration> {
+    (seconds > 0).then(|| std::time::Duration::from_secs(seconds))
 }
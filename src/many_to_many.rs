@@ -14,6 +14,13 @@ impl<TK, TV> ManyToMany<TK, TV> {
     }
 }
 
+// Derived, it would require the keys and values to implement Default too
+impl<TK, TV> Default for ManyToMany<TK, TV> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<TK: Eq + Hash, TV: Eq + Hash> ManyToMany<TK, TV> {
     pub fn insert(&mut self, key: TK, value: TV) -> bool {
         // result
@@ -82,14 +89,15 @@ impl<TK: Eq + Hash + Clone, TV: Eq + Hash> ManyToMany<TK, TV> {
         self.0.get_mut(key).unwrap()
     }
 
-    pub fn into_iter(self) -> impl Iterator<Item = (TK, TV)> {
+    // Every (key, value) pair, named so it isn't taken for IntoIterator::into_iter
+    pub fn into_pairs(self) -> impl Iterator<Item = (TK, TV)> {
         self.0
             .into_iter()
             .flat_map(|(key, set)| set.into_iter().map(move |value| (key.clone(), value)))
     }
     pub fn into_inverted(self) -> ManyToMany<TV, TK> {
         let mut result = ManyToMany::new();
-        for (key, value) in self.into_iter() {
+        for (key, value) in self.into_pairs() {
             result.insert(value, key);
         }
         result
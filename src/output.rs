@@ -0,0 +1,87 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::alloc::Allocs;
+use crate::runner::DayRun;
+use crate::solutions::{Answer, PartResult};
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: Option<Answer>,
+    pub duration_ns: Option<u128>,
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<Allocs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_allocs: Option<Allocs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'static str>,
+}
+
+impl Record {
+    pub fn new(year: usize, day: usize, result: &PartResult) -> Self {
+        Record {
+            year,
+            day,
+            part: result.part,
+            answer: result.answer.as_ref().ok().cloned(),
+            duration_ns: result
+                .answer
+                .as_ref()
+                .ok()
+                .map(|_| result.elapsed.as_nanos()),
+            error: result.answer.as_ref().err().map(|err| err.to_string()),
+            allocs: result.allocs,
+            input_allocs: result.input_allocs,
+            expected: None,
+            status: None,
+        }
+    }
+
+    pub fn failed(year: usize, day: usize, part: usize, error: &str) -> Self {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            duration_ns: None,
+            error: Some(error.to_string()),
+            allocs: None,
+            input_allocs: None,
+            expected: None,
+            status: None,
+        }
+    }
+}
+
+pub fn day_records(run: &DayRun, parts: usize) -> Vec<Record> {
+    match &run.results {
+        Ok(results) => results
+            .iter()
+            .map(|r| Record::new(run.year, run.day, r))
+            .collect(),
+        Err(error) => [1, 2]
+            .into_iter()
+            .filter(|part| parts & part > 0)
+            .map(|part| Record::failed(run.year, run.day, part, error))
+            .collect(),
+    }
+}
+
+pub fn print_json(records: &[Record]) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(records).expect("records are serializable")
+    );
+}
@@ -0,0 +1,163 @@
+use regex::{Captures, Regex};
+
+pub fn puzzle_file(year: usize, day: usize) -> String {
+    format!("puzzles/{year}/{:02}.md", day)
+}
+
+// The puzzle page has one <article> per part, part 2 appears once part 1 is solved
+pub fn articles(html: &str) -> Vec<&str> {
+    let regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    regex
+        .captures_iter(html)
+        .map(|res| res.get(1).unwrap().as_str())
+        .collect()
+}
+
+pub fn to_markdown(articles: &[&str]) -> String {
+    articles
+        .iter()
+        .map(|article| article_to_markdown(article))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+// First code block of the puzzle, which is almost always the example input
+pub fn example_input(article: &str) -> Option<String> {
+    let regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let res = regex.captures(article)?;
+    Some(unescape(&strip_tags(&res[1])))
+}
+
+// The answer for the example is usually the last highlighted value of the part
+pub fn example_answer(article: &str) -> Option<String> {
+    let regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    let res = regex.captures_iter(article).last()?;
+    Some(unescape(&strip_tags(&res[1])))
+}
+
+fn article_to_markdown(article: &str) -> String {
+    // Code blocks are set aside first so the inline rules don't touch them
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let mut blocks = vec![];
+    let mut text = pre
+        .replace_all(article, |res: &Captures| {
+            blocks.push(unescape(&strip_tags(&res[1])));
+            format!("\n\n\u{0}{}\u{0}\n\n", blocks.len() - 1)
+        })
+        .to_string();
+
+    let code = Regex::new(r"(?s)<code>(.*?)</code>").unwrap();
+    text = code
+        .replace_all(&text, |res: &Captures| {
+            let inner = strip_tags(&res[1]);
+            if res[1].contains("<em") {
+                format!("**`{inner}`**")
+            } else {
+                format!("`{inner}`")
+            }
+        })
+        .to_string();
+
+    let rules = [
+        (r"(?s)<h2[^>]*>(.*?)</h2>", "\n\n## $1\n\n"),
+        (r"(?s)<em[^>]*>(.*?)</em>", "**$1**"),
+        (r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#, "[$2]($1)"),
+        (r"(?s)<li>(.*?)</li>", "\n- $1"),
+        (r"</?ul>", "\n\n"),
+        (r"(?s)<p>(.*?)</p>", "\n\n$1\n\n"),
+    ];
+    for (pattern, replacement) in rules {
+        text = Regex::new(pattern)
+            .unwrap()
+            .replace_all(&text, replacement)
+            .to_string();
+    }
+    text = unescape(&strip_tags(&text));
+
+    let blank_lines = Regex::new(r"\n\s*\n(\s*\n)+").unwrap();
+    text = blank_lines.replace_all(text.trim(), "\n\n").to_string();
+
+    let placeholder = Regex::new(r"\x00(\d+)\x00").unwrap();
+    placeholder
+        .replace_all(&text, |res: &Captures| {
+            let block = &blocks[res[1].parse::<usize>().unwrap()];
+            format!("```\n{}\n```", block.trim_end_matches('\n'))
+        })
+        .to_string()
+}
+
+fn strip_tags(html: &str) -> String {
+    Regex::new(r"<[^>]*>")
+        .unwrap()
+        .replace_all(html, "")
+        .to_string()
+}
+
+fn unescape(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = "<article class=\"day-desc\"><h2>--- Day 5: Print Queue ---</h2>\
+<p>The first section specifies the <em>page ordering rules</em>, one per line:</p>
+<pre><code>47|53
+97|13
+&lt;end&gt;
+</code></pre>
+<ul>
+<li>The first update, <code>75,47,61,53,29</code>, is in the <em>right order</em>.</li>
+<li>See <a href=\"https://en.wikipedia.org/wiki/Printing\" target=\"_blank\">printing</a>.</li>
+</ul>
+<p>Adding these together produces <code><em>143</em></code>.</p>
+<p><em>What do you get</em> if you add up the middle page numbers?</p>
+</article>";
+
+    #[test]
+    fn articles_of_a_page() {
+        let page = format!(
+            "<main>{ARTICLE}<p>Your puzzle answer was <code>42</code>.</p>{ARTICLE}</main>"
+        );
+        assert_eq!(articles(&page).len(), 2);
+    }
+
+    #[test]
+    fn markdown() {
+        let expected = "\
+## --- Day 5: Print Queue ---
+
+The first section specifies the **page ordering rules**, one per line:
+
+```
+47|53
+97|13
+<end>
+```
+
+- The first update, `75,47,61,53,29`, is in the **right order**.
+
+- See [printing](https://en.wikipedia.org/wiki/Printing).
+
+Adding these together produces **`143`**.
+
+**What do you get** if you add up the middle page numbers?";
+        assert_eq!(article_to_markdown(ARTICLE), expected);
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            example_input(ARTICLE).as_deref(),
+            Some("47|53\n97|13\n<end>\n")
+        );
+        assert_eq!(example_answer(ARTICLE).as_deref(), Some("143"));
+        assert_eq!(example_answer("<p>No answer here</p>"), None);
+    }
+}
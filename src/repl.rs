@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use crate::alloc;
+use crate::bench::Stats;
+use crate::runner::{self, panic_message};
+use crate::solutions::{self, get_elapsed, DynSolver, Failure, Param, Params, PartResult, Session};
+
+const HELP: &str = "\
+run [part]          run part 1, 2 or both (3, the default)
+time <part> [runs]  time a part over several runs, 10 by default
+param [name=value]  show the parameters or change one
+reset [name]        put a parameter, or all of them, back to its default
+dump [name]         print a structure of the parsed input, or list them
+reload              read and parse the input again, needed for parameters used while parsing
+help                show this
+quit                leave, so does the end of the input";
+
+fn parse<'a>(
+    solver: &'a dyn DynSolver,
+    path: &str,
+    params: &Params,
+) -> Result<Box<dyn Session + 'a>, String> {
+    let contents = runner::read_input(path).map_err(|err| format!("{path}: {err}"))?;
+    let start = Instant::now();
+    let (session, allocs) = alloc::measure(|| solver.parse(&contents, params));
+    let session = session.map_err(|err| format!("invalid input: {err:#}"))?;
+    match allocs {
+        Some(allocs) => println!(
+            "Parsed {path} in {} ({})",
+            get_elapsed(start.elapsed()),
+            allocs.summary()
+        ),
+        None => println!("Parsed {path} in {}", get_elapsed(start.elapsed())),
+    }
+    Ok(session)
+}
+
+// A panicking part shouldn't end the session
+fn run(session: &dyn Session, parts: &str, params: &Params) -> Result<(), String> {
+    let parts = get_part(parts)?;
+    for part in [1, 2].into_iter().filter(|part| parts & part > 0) {
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| session.solve(part, params)))
+            .unwrap_or_else(|payload| {
+                let failure = Failure::Panicked(panic_message(payload));
+                PartResult::failed(part, failure, start.elapsed())
+            });
+        println!("{result}");
+    }
+    Ok(())
+}
+
+fn time(session: &dyn Session, part: &str, runs: &str, params: &Params) -> Result<(), String> {
+    let part = match get_part(part)? {
+        3 => return Err(String::from("time one part at a time")),
+        part => part,
+    };
+    let runs = runs
+        .parse()
+        .ok()
+        .filter(|&runs| runs > 0)
+        .ok_or_else(|| format!("invalid number of runs `{runs}`"))?;
+    let durations = panic::catch_unwind(AssertUnwindSafe(|| session.time(part, runs, params)))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload)))?
+        .map_err(|err| format!("failed: {err}"))?;
+    println!("Part {}: {}", part, Stats::new(&durations));
+    Ok(())
+}
+
+fn dump(session: &dyn Session, name: &str, params: &Params) -> Result<(), String> {
+    let dump = panic::catch_unwind(AssertUnwindSafe(|| session.dump(name, params)))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload)))?
+        .ok_or_else(|| format!("nothing named `{name}` to dump, see `dump`"))?;
+    println!("{dump}");
+    Ok(())
+}
+
+fn get_part(part: &str) -> Result<usize, String> {
+    match part.parse() {
+        Ok(part @ 1..=3) => Ok(part),
+        _ => Err(format!("invalid part `{part}`, expected 1, 2 or 3")),
+    }
+}
+
+fn print_params(declared: &[Param], params: &Params) {
+    if declared.is_empty() {
+        println!("No parameters are declared");
+    }
+    for param in declared {
+        match params.get(param.name) {
+            Some(value) => println!(
+                "{} = {} (default {}): {}",
+                param.name, value, param.default, param.help
+            ),
+            None => println!("{} = {}: {}", param.name, param.default, param.help),
+        }
+    }
+}
+
+fn get_param<'a>(declared: &'a [Param], name: &str) -> Result<&'a Param, String> {
+    declared
+        .iter()
+        .find(|param| param.name == name)
+        .ok_or_else(|| format!("unknown parameter `{name}`"))
+}
+
+// Parses `day`'s input once and reads commands from stdin to explore it
+pub fn repl(year: usize, day: usize, path: &str, mut params: Params) -> Result<(), Box<dyn Error>> {
+    if path == "-" {
+        return Err("the repl reads its commands from stdin, pass the input as a file".into());
+    }
+    let solver = solutions::get_solver(year, day).ok_or("day not implemented")?;
+    let declared = solver.params();
+    let mut session = parse(solver, path, &params)?;
+    println!("Type `help` for the commands");
+
+    let stdin = io::stdin();
+    loop {
+        print!("day{:02}> ", day);
+        io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            println!();
+            return Ok(());
+        }
+
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let result = match words[..] {
+            [] => Ok(()),
+            ["help"] => {
+                println!("{HELP}");
+                Ok(())
+            }
+            ["quit"] | ["exit"] => return Ok(()),
+            ["run"] => run(session.as_ref(), "3", &params),
+            ["run", parts] => run(session.as_ref(), parts, &params),
+            ["time", part] => time(session.as_ref(), part, "10", &params),
+            ["time", part, runs] => time(session.as_ref(), part, runs, &params),
+            ["param"] => {
+                print_params(&declared, &params);
+                Ok(())
+            }
+            ["param", value] => value.parse::<solutions::ParamValue>().and_then(|value| {
+                get_param(&declared, &value.name)?;
+                params.insert(value.name, value.value);
+                Ok(())
+            }),
+            ["reset"] => {
+                params.clear();
+                Ok(())
+            }
+            ["reset", name] => get_param(&declared, name).map(|param| {
+                params.remove(param.name);
+            }),
+            ["dump"] => {
+                match solver.dumps()[..] {
+                    [] => println!("Nothing to dump"),
+                    ref names => println!("{}", names.join(", ")),
+                }
+                Ok(())
+            }
+            ["dump", name] => dump(session.as_ref(), name, &params),
+            ["reload"] => parse(solver, path, &params).map(|reloaded| {
+                session = reloaded;
+            }),
+            _ => Err(format!("unknown command `{}`, try `help`", line.trim())),
+        };
+        if let Err(err) = result {
+            println!("Error: {err}");
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::history;
+use crate::runner::DayRun;
+use crate::solutions::{get_elapsed, Answer, Params, PartResult};
+use crate::verify;
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { padding: 0.3em 0.8em; text-align: left; vertical-align: top; }
+tr:nth-child(even) { background: #f4f4f4; }
+code, pre { font-family: monospace; white-space: pre; }
+pre.visualization { font-size: 0.5em; line-height: 1; overflow-x: auto; }
+.pass { color: #1a7f37; }
+.changed, .failed { color: #cf222e; }
+.unverified { color: #777; }
+.timings td { padding: 0.1em 0.8em; }
+.bar { height: 0.8em; min-width: 1px; display: inline-block; }
+.parse { background: #8c959f; }
+.part1 { background: #0969da; }
+.part2 { background: #8250df; }
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Class and text of a part's cell when compared to its stored answer
+fn status(result: &PartResult, expected: Option<&Answer>) -> (&'static str, String) {
+    match (&result.answer, expected) {
+        (Ok(answer), Some(expected)) if answer == expected => ("pass", String::from("pass")),
+        (Ok(_), Some(expected)) => ("changed", format!("changed, expected {expected}")),
+        (Ok(_), None) => ("unverified", String::from("no stored answer")),
+        (Err(failure), _) => ("failed", failure.to_string()),
+    }
+}
+
+fn answers(html: &mut String, runs: &[DayRun]) {
+    html.push_str("<h2>Answers</h2>\n<table>\n");
+    html.push_str("<tr><th>Day</th><th>Part 1</th><th></th><th>Part 2</th><th></th></tr>\n");
+    for run in runs {
+        write!(html, "<tr><td>{:02}</td>", run.day).unwrap();
+        match &run.results {
+            Ok(results) => {
+                let expected = verify::read_answers(run.year, run.day).unwrap_or_default();
+                for part in [1, 2] {
+                    let Some(result) = results.iter().find(|r| r.part == part) else {
+                        html.push_str("<td></td><td></td>");
+                        continue;
+                    };
+                    let answer = result
+                        .answer
+                        .as_ref()
+                        .map_or(String::new(), Answer::to_string);
+                    let (class, text) = status(result, expected.get(&part));
+                    write!(
+                        html,
+                        "<td><code>{}</code></td><td class=\"{}\">{}</td>",
+                        escape(&answer),
+                        class,
+                        escape(&text)
+                    )
+                    .unwrap();
+                }
+            }
+            Err(err) => write!(
+                html,
+                "<td colspan=\"4\" class=\"failed\">{}</td>",
+                escape(err)
+            )
+            .unwrap(),
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+// Times span from nanoseconds to seconds, so the bars use a log scale from
+// 1 μs to the slowest time of the run
+fn bar_width(time: Duration, slowest: Duration) -> f64 {
+    let floor = Duration::from_micros(1);
+    if slowest <= floor {
+        return 100.0;
+    }
+    let log = |d: Duration| (d.max(floor).as_nanos() as f64).ln();
+    100.0 * (log(time) - log(floor)) / (log(slowest) - log(floor))
+}
+
+fn timings(html: &mut String, runs: &[DayRun]) {
+    let rows = runs
+        .iter()
+        .filter_map(|run| Some((run.day, run.results.as_ref().ok()?)))
+        .map(|(day, results)| {
+            let parse = results.iter().find_map(|r| r.input_elapsed);
+            let parts = [1, 2].map(|part| {
+                results
+                    .iter()
+                    .find(|r| r.part == part && r.answer.is_ok())
+                    .map(|r| r.elapsed)
+            });
+            (day, [parse, parts[0], parts[1]])
+        })
+        .collect::<Vec<_>>();
+    let slowest = rows
+        .iter()
+        .flat_map(|(_, times)| times.iter().flatten())
+        .max()
+        .copied()
+        .unwrap_or_default();
+
+    html.push_str("<h2>Timings</h2>\n");
+    html.push_str("<p>Bars use a log scale from 1 μs to the slowest time.</p>\n");
+    html.push_str("<table class=\"timings\">\n");
+    for (day, times) in rows {
+        let labels = [("Parse", "parse"), ("Part 1", "part1"), ("Part 2", "part2")];
+        let mut day = format!("{:02}", day);
+        for ((label, class), time) in labels.into_iter().zip(times) {
+            let Some(time) = time else {
+                continue;
+            };
+            writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td style=\"width: 30em\">\
+                 <span class=\"bar {}\" style=\"width: {:.1}%\"></span></td><td>{}</td></tr>",
+                day,
+                label,
+                class,
+                bar_width(time, slowest),
+                get_elapsed(time)
+            )
+            .unwrap();
+            // Only the first row of a day is labelled with it
+            day.clear();
+        }
+    }
+    html.push_str("</table>\n");
+}
+
+// Drawn by run_day from the inputs it parsed, a drawing that failed is noted
+fn visualizations(html: &mut String, runs: &[DayRun]) {
+    let drawn = runs
+        .iter()
+        .filter_map(|run| Some((run.day, run.visualization.as_ref()?)))
+        .collect::<Vec<_>>();
+    if drawn.is_empty() {
+        return;
+    }
+
+    html.push_str("<h2>Visualizations</h2>\n");
+    for (day, picture) in drawn {
+        writeln!(html, "<h3>Day {:02}</h3>", day).unwrap();
+        match picture {
+            Ok(picture) => writeln!(
+                html,
+                "<pre class=\"visualization\">{}</pre>",
+                escape(picture)
+            ),
+            Err(failure) => writeln!(
+                html,
+                "<p class=\"failed\">{}</p>",
+                escape(&failure.to_string())
+            ),
+        }
+        .unwrap();
+    }
+}
+
+// Writes a single HTML file with no external resources, so it can be shared as is
+pub fn write(
+    path: &str,
+    year: usize,
+    runs: &[DayRun],
+    total: Duration,
+    params: &Params,
+) -> std::io::Result<()> {
+    let mut html = String::new();
+    write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code {year}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+         <h1>Advent of Code {year}</h1>\n<p>{} days in {} at commit <code>{}</code>, {}</p>\n",
+        runs.len(),
+        get_elapsed(total),
+        escape(&history::current_commit()),
+        chrono::Local::now().format("%Y-%m-%d %H:%M")
+    )
+    .unwrap();
+    if !params.is_empty() {
+        let params = params
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(html, "<p>Parameters: <code>{}</code></p>", escape(&params)).unwrap();
+    }
+    answers(&mut html, runs);
+    timings(&mut html, runs);
+    visualizations(&mut html, runs);
+    html.push_str("</body>\n</html>\n");
+    std::fs::write(path, html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::Failure;
+
+    fn result(answer: Result<Answer, Failure>) -> PartResult {
+        PartResult {
+            part: 1,
+            answer,
+            elapsed: Duration::ZERO,
+            allocs: None,
+            input_allocs: None,
+            input_elapsed: None,
+        }
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+        assert_eq!(escape("&lt;"), "&amp;lt;");
+    }
+
+    #[test]
+    fn statuses() {
+        let expected = Answer::Int(42);
+        let pass = result(Ok(Answer::Int(42)));
+        assert_eq!(
+            status(&pass, Some(&expected)),
+            ("pass", String::from("pass"))
+        );
+        let changed = result(Ok(Answer::Int(41)));
+        assert_eq!(
+            status(&changed, Some(&expected)),
+            ("changed", String::from("changed, expected 42"))
+        );
+        assert_eq!(
+            status(&changed, None),
+            ("unverified", String::from("no stored answer"))
+        );
+        let failed = result(Err(Failure::Error(String::from("no path"))));
+        assert_eq!(
+            status(&failed, Some(&expected)),
+            ("failed", String::from("errored: no path"))
+        );
+    }
+
+    #[test]
+    fn bar_widths() {
+        let slowest = Duration::from_secs(1);
+        assert_eq!(bar_width(Duration::from_micros(1), slowest), 0.0);
+        // Anything faster than the floor is drawn as the floor
+        assert_eq!(bar_width(Duration::from_nanos(10), slowest), 0.0);
+        assert_eq!(bar_width(slowest, slowest), 100.0);
+        assert!((bar_width(Duration::from_millis(1), slowest) - 50.0).abs() < 1e-9);
+        // A run that is all faster than the floor fills the bars
+        assert_eq!(
+            bar_width(Duration::from_nanos(10), Duration::from_nanos(500)),
+            100.0
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use std::any::Any;
+use std::error::Error;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Semaphore;
+
+use crate::alloc::{self, Allocs};
+use crate::history;
+use crate::output::{self, Format};
+use crate::report;
+use crate::solutions::{self, get_elapsed, Failure, Params, ParseError, PartResult, Session};
+
+pub struct DayRun {
+    pub year: usize,
+    pub day: usize,
+    // Err holds why the day couldn't be solved: a missing or invalid input, or a panic
+    pub results: Result<Vec<PartResult>, String>,
+    // Drawn from the parsed input for reports, when the day can draw something
+    pub visualization: Option<Result<String, Failure>>,
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+pub fn read_input(path: &str) -> std::io::Result<Arc<[u8]>> {
+    let mut contents = vec![];
+    solutions::open_input(path)?.read_to_end(&mut contents)?;
+    Ok(contents.into())
+}
+
+// A thread that runs away can't be stopped, so each part gets a detached thread
+// sharing the parsed input, and is abandoned if it takes too long
+fn spawn_part<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+) -> mpsc::Receiver<Result<T, String>> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let outcome = panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message);
+        sender.send(outcome).ok();
+    });
+    receiver
+}
+
+// Waits for a spawned part until `timeout` after `start`
+fn wait_part<T>(
+    receiver: mpsc::Receiver<Result<T, String>>,
+    start: Instant,
+    timeout: Option<Duration>,
+) -> Result<T, Failure> {
+    let outcome = match timeout {
+        Some(timeout) => receiver
+            .recv_timeout(timeout.saturating_sub(start.elapsed()))
+            .map_err(|_| Failure::TimedOut(timeout))?,
+        None => receiver
+            .recv()
+            .map_err(|_| Failure::Panicked(String::from("part thread died")))?,
+    };
+    outcome.map_err(Failure::Panicked)
+}
+
+fn part_result(part: usize, outcome: Result<PartResult, Failure>, start: Instant) -> PartResult {
+    outcome.unwrap_or_else(|failure| PartResult::failed(part, failure, start.elapsed()))
+}
+
+// Parses the input once, then runs the selected parts on it under a watchdog.
+// Panics and parts running past `timeout` are reported in their result, only
+// an invalid input fails the day.
+pub fn solve_parts(
+    year: usize,
+    day: usize,
+    input: Arc<[u8]>,
+    parts: usize,
+    parallel: bool,
+    timeout: Option<Duration>,
+    params: &Params,
+) -> Result<Vec<PartResult>, ParseError> {
+    solve_session(year, day, input, parts, parallel, timeout, params).map(|solved| solved.results)
+}
+
+struct Solved {
+    results: Vec<PartResult>,
+    // None when parsing panicked
+    session: Option<Arc<dyn Session>>,
+}
+
+// solve_parts that also hands back the parsed input
+fn solve_session(
+    year: usize,
+    day: usize,
+    input: Arc<[u8]>,
+    parts: usize,
+    parallel: bool,
+    timeout: Option<Duration>,
+    params: &Params,
+) -> Result<Solved, ParseError> {
+    let solver = solutions::get_solver(year, day).expect("day not implemented");
+    let selected = [1, 2]
+        .into_iter()
+        .filter(|part| parts & 1 << (part - 1) > 0)
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let (parsed, input_allocs) =
+        alloc::measure(|| panic::catch_unwind(AssertUnwindSafe(|| solver.parse(&input, params))));
+    let input_elapsed = start.elapsed();
+    let session: Arc<dyn Session> = match parsed {
+        Ok(session) => Arc::from(session?),
+        // Without an input no part can run, they all fail the same way
+        Err(payload) => {
+            let failure = Failure::Panicked(panic_message(payload));
+            let results = selected
+                .into_iter()
+                .map(|part| PartResult::failed(part, failure.clone(), input_elapsed))
+                .collect();
+            return Ok(Solved {
+                results,
+                session: None,
+            });
+        }
+    };
+
+    let spawn = |part: usize| {
+        let (session, params) = (session.clone(), params.clone());
+        spawn_part(move || session.solve(part, &params))
+    };
+    let mut results = vec![];
+    if parallel {
+        let spawned = selected
+            .into_iter()
+            .map(|part| (part, spawn(part), Instant::now()))
+            .collect::<Vec<_>>();
+        for (part, receiver, start) in spawned {
+            results.push(part_result(
+                part,
+                wait_part(receiver, start, timeout),
+                start,
+            ));
+        }
+    } else {
+        for part in selected {
+            let start = Instant::now();
+            let receiver = spawn(part);
+            results.push(part_result(
+                part,
+                wait_part(receiver, start, timeout),
+                start,
+            ));
+        }
+    }
+    for result in results.iter_mut() {
+        result.input_allocs = input_allocs;
+        result.input_elapsed = Some(input_elapsed);
+    }
+    Ok(Solved {
+        results,
+        session: Some(session),
+    })
+}
+
+pub fn run_day(year: usize, day: usize, options: &RunOptions) -> Option<DayRun> {
+    solutions::get_solver(year, day)?;
+    let solved = read_input(&solutions::input_file(year, day))
+        .map_err(|err| err.to_string())
+        .and_then(|input| {
+            solve_session(
+                year,
+                day,
+                input,
+                options.parts,
+                options.parallel,
+                options.timeout,
+                &options.params,
+            )
+            .map_err(|err| format!("invalid input: {err}"))
+        });
+    let (results, session) = match solved {
+        Ok(solved) => (Ok(solved.results), solved.session),
+        Err(err) => (Err(err), None),
+    };
+
+    // Drawing runs under the same watchdog as the parts
+    let visualization = session
+        .filter(|_| options.report.is_some())
+        .and_then(|session| {
+            let start = Instant::now();
+            let params = options.params.clone();
+            let receiver = spawn_part(move || session.visualize(&params));
+            wait_part(receiver, start, options.timeout).transpose()
+        });
+
+    Some(DayRun {
+        year,
+        day,
+        results,
+        visualization,
+    })
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+// Panics are reported in the results, don't let the default hook spam stderr
+fn silence_panics() -> PanicHook {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    hook
+}
+
+// Parts that timed out are still running and may panic later, keep them quiet
+fn restore_panics(hook: PanicHook, runs: &[DayRun]) {
+    let timed_out = runs.iter().any(|run| {
+        run.results
+            .iter()
+            .flatten()
+            .any(|result| matches!(result.answer, Err(Failure::TimedOut(_))))
+            || matches!(run.visualization, Some(Err(Failure::TimedOut(_))))
+    });
+    if !timed_out {
+        panic::set_hook(hook);
+    }
+}
+
+// Runs up to `options.jobs` days at the same time, results are returned in the order of `days`
+pub async fn run_days(
+    year: usize,
+    days: &[usize],
+    options: &RunOptions,
+) -> (Vec<DayRun>, Duration) {
+    let hook = silence_panics();
+    let start = Instant::now();
+    let semaphore = Arc::new(Semaphore::new(options.jobs.max(1)));
+    let mut handles = vec![];
+    for &day in days {
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+        let options = options.clone();
+        handles.push(tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            run_day(year, day, &options)
+        }));
+    }
+    let mut runs = vec![];
+    for handle in handles {
+        runs.extend(handle.await.expect("runner task failed"));
+    }
+    let total = start.elapsed();
+    restore_panics(hook, &runs);
+
+    (runs, total)
+}
+
+pub fn print_table(runs: &[DayRun], total: Duration) {
+    // Allocation counts and peak heap usage get their own columns when measured
+    let memory = cfg!(feature = "alloc-stats");
+    let allocs = |allocs: Option<Allocs>| {
+        allocs.map_or(String::new(), |a| {
+            format!("{} / {}", a.count, alloc::format_bytes(a.peak_bytes))
+        })
+    };
+    let mut header = vec!["Day", "Part 1", "Time", "Part 2", "Time", "Status"];
+    if memory {
+        header.insert(5, "Memory");
+        header.insert(3, "Memory");
+        header.insert(1, "Input");
+    }
+    let mut rows = vec![header.into_iter().map(String::from).collect::<Vec<_>>()];
+
+    for run in runs {
+        let mut row = vec![format!("{:02}", run.day)];
+        match &run.results {
+            Ok(results) => {
+                if memory {
+                    row.push(allocs(results.first().and_then(|r| r.input_allocs)));
+                }
+                let mut errors = vec![];
+                for part in [1, 2] {
+                    match results.iter().find(|r| r.part == part) {
+                        Some(PartResult {
+                            answer: Ok(answer),
+                            elapsed,
+                            allocs: part_allocs,
+                            ..
+                        }) => {
+                            row.push(answer.to_line());
+                            row.push(get_elapsed(*elapsed));
+                            if memory {
+                                row.push(allocs(*part_allocs));
+                            }
+                        }
+                        Some(PartResult {
+                            answer: Err(failure),
+                            ..
+                        }) => {
+                            errors.push(format!("part {part} {failure}"));
+                            row.extend([String::from("-"), String::new()]);
+                            if memory {
+                                row.push(String::new());
+                            }
+                        }
+                        None => {
+                            row.extend([String::new(), String::new()]);
+                            if memory {
+                                row.push(String::new());
+                            }
+                        }
+                    }
+                }
+                row.push(if errors.is_empty() {
+                    String::from("ok")
+                } else {
+                    errors.join(", ")
+                });
+            }
+            Err(msg) => {
+                row.resize(rows[0].len() - 1, String::new());
+                row.push(msg.clone());
+            }
+        }
+        rows.push(row);
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).max().unwrap())
+        .collect::<Vec<_>>();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+    println!("Total: {}", get_elapsed(total));
+}
+
+// How `run` runs the days it's given
+#[derive(Clone)]
+pub struct RunOptions {
+    pub parts: usize,
+    // Runs the examples instead of the inputs
+    pub example: bool,
+    // Input file of a single day to use instead of inputs/YYYY/DD, `-` for stdin
+    pub input: Option<String>,
+    pub format: Format,
+    // Number of days to run at the same time
+    pub jobs: usize,
+    // Runs part 1 and part 2 of each day on separate threads
+    pub parallel: bool,
+    pub timeout: Option<Duration>,
+    pub params: Params,
+    // HTML report of the run to write
+    pub report: Option<String>,
+}
+
+impl RunOptions {
+    // Timings of other inputs, other parameters, or taken while other parts
+    // competed for the CPU aren't comparable
+    fn comparable(&self, year: usize, day: usize, days: usize) -> bool {
+        let default_input = self
+            .input
+            .as_ref()
+            .is_none_or(|input| *input == solutions::input_file(year, day));
+        default_input && self.params.is_empty() && !self.parallel && (days == 1 || self.jobs <= 1)
+    }
+}
+
+fn record(runs: &[DayRun], options: &RunOptions) {
+    for run in runs {
+        if !options.comparable(run.year, run.day, runs.len()) {
+            continue;
+        }
+        if let Ok(results) = &run.results {
+            if let Err(err) = history::record(run.year, run.day, results) {
+                eprintln!("Couldn't record timings: {err}");
+            }
+        }
+    }
+}
+
+// Runs `days` and prints their answers, part by part for a single day and as
+// a table for several. Comparable timings are recorded to the history.
+pub async fn run(year: usize, days: &[usize], options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    if options.example {
+        return run_examples(year, days, options);
+    }
+    // Reports are made from the multi day run, even for a single day
+    match days {
+        [day] if options.report.is_none() => run_single(year, *day, options),
+        _ if options.input.is_some() => Err("--input can only be used with a single day".into()),
+        _ => {
+            let (runs, total) = run_days(year, days, options).await;
+            record(&runs, options);
+            match options.format {
+                Format::Text => print_table(&runs, total),
+                Format::Json => {
+                    let records = runs
+                        .iter()
+                        .flat_map(|run| output::day_records(run, options.parts));
+                    output::print_json(&records.collect::<Vec<_>>());
+                }
+            }
+            if let Some(path) = &options.report {
+                report::write(path, year, &runs, total, &options.params)
+                    .map_err(|err| format!("{path}: {err}"))?;
+                if options.format == Format::Text {
+                    println!("Report written to {path}");
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn run_examples(year: usize, days: &[usize], options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    if let [day] = days[..] {
+        let solver = solutions::get_solver(year, day).ok_or("day not implemented")?;
+        if !solver.solve_examples(options.parts, &options.params) {
+            return Err("examples failed".into());
+        }
+        return Ok(());
+    }
+    let mut failed = vec![];
+    for &day in days {
+        if let Some(solver) = solutions::get_solver(year, day) {
+            println!("Day {:02}", day);
+            if !solver.solve_examples(options.parts, &options.params) {
+                failed.push(format!("{:02}", day));
+            }
+        }
+    }
+    match failed[..] {
+        [] => Ok(()),
+        [ref day] => Err(format!("examples of day {day} failed").into()),
+        _ => Err(format!("examples of days {} failed", failed.join(", ")).into()),
+    }
+}
+
+fn run_single(year: usize, day: usize, options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    solutions::get_solver(year, day).ok_or("day not implemented")?;
+    let filename = options
+        .input
+        .clone()
+        .unwrap_or_else(|| solutions::input_file(year, day));
+    let hook = silence_panics();
+    let results = read_input(&filename)
+        .map_err(|err| err.to_string())
+        .and_then(|input| {
+            solve_parts(
+                year,
+                day,
+                input,
+                options.parts,
+                options.parallel,
+                options.timeout,
+                &options.params,
+            )
+            .map_err(|err| match options.format {
+                Format::Text => {
+                    eprintln!("{:#}", err.day(day));
+                    String::from("invalid input")
+                }
+                Format::Json => format!("invalid input: {err}"),
+            })
+        });
+    // Scripts get failed records rather than no JSON at all
+    let run = DayRun {
+        year,
+        day,
+        results,
+        visualization: None,
+    };
+    restore_panics(hook, std::slice::from_ref(&run));
+    match (options.format, &run.results) {
+        (Format::Text, Ok(results)) => {
+            if let Some(allocs) = results.first().and_then(|r| r.input_allocs) {
+                println!("Input: {}", allocs.summary());
+            }
+            for result in results.iter() {
+                println!("{result}");
+            }
+        }
+        (Format::Text, Err(_)) => {}
+        (Format::Json, _) => output::print_json(&output::day_records(&run, options.parts)),
+    }
+    record(std::slice::from_ref(&run), options);
+    run.results?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finished_parts() {
+        let start = Instant::now();
+        let outcome = wait_part(spawn_part(|| 42), start, Some(Duration::from_secs(5)));
+        assert!(matches!(outcome, Ok(42)));
+        assert!(matches!(wait_part(spawn_part(|| 42), start, None), Ok(42)));
+    }
+
+    #[test]
+    fn timed_out_parts() {
+        let timeout = Duration::from_millis(50);
+        let receiver = spawn_part(|| std::thread::sleep(Duration::from_secs(2)));
+        let start = Instant::now();
+        let outcome = wait_part(receiver, start, Some(timeout));
+        assert!(matches!(outcome, Err(Failure::TimedOut(limit)) if limit == timeout));
+        // The part is abandoned rather than waited for
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn panicked_parts() {
+        let receiver = spawn_part(|| -> usize { panic!("no path to the exit") });
+        let outcome = wait_part(receiver, Instant::now(), Some(Duration::from_secs(5)));
+        assert!(matches!(outcome, Err(Failure::Panicked(msg)) if msg == "no path to the exit"));
+    }
+}
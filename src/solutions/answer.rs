@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use num::BigInt;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// A puzzle answer, however the solver computed it. Answers built from the same
+// text are equal, so a stored `"42"` matches a computed `42usize`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    // Integers that don't fit an i128
+    BigInt(BigInt),
+    Text(String),
+    // ASCII art and other answers spanning several lines
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    // Only integers written the way they print are numbers, so answers such as
+    // `007` or `+5` keep their text
+    fn from_text(text: &str) -> Answer {
+        if text.contains('\n') {
+            return Answer::Lines(text.lines().map(String::from).collect());
+        }
+        match (text.parse::<i128>(), text.parse::<BigInt>()) {
+            (Ok(int), _) if int.to_string() == text => Answer::Int(int),
+            (_, Ok(int)) if int.to_string() == text => Answer::BigInt(int),
+            _ => Answer::Text(text.to_string()),
+        }
+    }
+
+    // Single line form for the line based answers and submissions files
+    pub fn to_line(&self) -> String {
+        self.to_string().replace('\n', "\\n")
+    }
+
+    pub fn from_line(line: &str) -> Answer {
+        Answer::from_text(&line.replace("\\n", "\n"))
+    }
+
+    fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Int(int) => Some(BigInt::from(*int)),
+            Answer::BigInt(int) => Some(int.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(int) => write!(f, "{}", int),
+            Answer::BigInt(int) => write!(f, "{}", int),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+// Only integers are ordered, which is what too high and too low are about
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => Some(a.cmp(b)),
+            _ => Some(self.to_bigint()?.cmp(&other.to_bigint()?)),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::from_text(s))
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::from_text(text)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::from_text(&text)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::from_text(&lines.join("\n"))
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(int: BigInt) -> Self {
+        Answer::from_text(&int.to_string())
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(int: $t) -> Self {
+                Answer::Int(int as i128)
+            }
+        })*
+    };
+}
+
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(int: u128) -> Self {
+        match i128::try_from(int) {
+            Ok(int) => Answer::Int(int),
+            Err(_) => Answer::BigInt(BigInt::from(int)),
+        }
+    }
+}
+
+// Serialised as the answer's text, like in the answers files
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Answer::from_text(&String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_are_canonical() {
+        assert_eq!(Answer::from("42"), Answer::Int(42));
+        assert_eq!(Answer::from("-42"), Answer::Int(-42));
+        assert_eq!(Answer::from("007"), Answer::Text(String::from("007")));
+        assert_eq!(Answer::from("+5"), Answer::Text(String::from("+5")));
+        assert_eq!(Answer::from("-0"), Answer::Text(String::from("-0")));
+        assert_eq!(Answer::from("007").to_string(), "007");
+    }
+
+    #[test]
+    fn int_and_bigint() {
+        let big = "1".repeat(50);
+        assert!(matches!(Answer::from(big.as_str()), Answer::BigInt(_)));
+        assert_eq!(Answer::from(BigInt::from(42)), Answer::from(42usize));
+        assert_eq!(Answer::from(BigInt::from(42)), Answer::Int(42));
+        assert_eq!(Answer::from(u128::MAX), Answer::from(u128::MAX.to_string()));
+        assert!(Answer::from(big.as_str()) > Answer::from(i128::MAX));
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Answer::from(5) < Answer::from(12));
+        assert!(Answer::from("abc")
+            .partial_cmp(&Answer::from("abd"))
+            .is_none());
+        assert!(Answer::from("abc").partial_cmp(&Answer::from(5)).is_none());
+    }
+
+    #[test]
+    fn lines() {
+        let answer = Answer::from(vec![String::from("#."), String::from(".#")]);
+        assert_eq!(
+            answer,
+            Answer::Lines(vec![String::from("#."), String::from(".#")])
+        );
+        assert_eq!(answer.to_line(), "#.\\n.#");
+        assert_eq!(Answer::from_line(&answer.to_line()), answer);
+        assert_eq!(Answer::from_line("42"), Answer::Int(42));
+    }
+
+    #[test]
+    fn serde() {
+        let json = serde_json::to_string(&Answer::from(42)).unwrap();
+        assert_eq!(json, "\"42\"");
+        assert_eq!(
+            serde_json::from_str::<Answer>(&json).unwrap(),
+            Answer::Int(42)
+        );
+        let json = serde_json::to_string(&Answer::from("007")).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Answer>(&json).unwrap(),
+            Answer::from("007")
+        );
+    }
+}
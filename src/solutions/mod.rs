@@ -1,58 +1,55 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
+mod answer;
+pub mod params;
+mod parse;
 mod solver;
+pub mod trace;
 
-pub use solver::Solver;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
 
-pub fn solve(day: usize, parts: usize) {
-    let filename = format!("inputs/{:02}", day);
-    match day {
-        1 => day01::Problem.solve(filename, parts),
-        2 => day02::Problem.solve(filename, parts),
-        3 => day03::Problem.solve(filename, parts),
-        4 => day04::Problem.solve(filename, parts),
-        5 => day05::Problem.solve(filename, parts),
-        6 => day06::Problem.solve(filename, parts),
-        7 => day07::Problem.solve(filename, parts),
-        8 => day08::Problem.solve(filename, parts),
-        9 => day09::Problem.solve(filename, parts),
-        10 => day10::Problem.solve(filename, parts),
-        11 => day11::Problem.solve(filename, parts),
-        12 => day12::Problem.solve(filename, parts),
-        13 => day13::Problem.solve(filename, parts),
-        14 => day14::Problem.solve(filename, parts),
-        15 => day15::Problem.solve(filename, parts),
-        16 => day16::Problem.solve(filename, parts),
-        17 => day17::Problem.solve(filename, parts),
-        18 => day18::Problem.solve(filename, parts),
-        19 => day19::Problem.solve(filename, parts),
-        20 => day20::Problem.solve(filename, parts),
-        21 => day21::Problem.solve(filename, parts),
-        22 => day22::Problem.solve(filename, parts),
-        23 => day23::Problem.solve(filename, parts),
-        24 => day24::Problem.solve(filename, parts),
-        _ => panic!("day not implemented"),
+pub use answer::Answer;
+pub use params::{Param, ParamValue, Params};
+pub use parse::{parse_field, parse_lines, read_lines, ParseError};
+pub use solver::{get_elapsed, DynSolver, Example, Failure, PartResult, Session, Solver};
+pub(crate) use trace::trace;
+
+// Year modules, YEARS, days and get_solver are generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+pub fn input_file(year: usize, day: usize) -> String {
+    format!("inputs/{year}/{:02}", day)
+}
+
+// `-` reads the input from stdin
+pub fn open_input(path: &str) -> Result<Box<dyn BufRead>, io::Error> {
+    if path == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file =
+            File::open(path).map_err(|err| io::Error::new(err.kind(), format!("{path}: {err}")))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+// Prints the solved days of `years`, noting missing inputs and the parameters
+// with their defaults
+pub fn list(years: &[usize]) {
+    for &year in years {
+        for &day in days(year) {
+            let mut notes = vec![];
+            if !Path::new(&input_file(year, day)).exists() {
+                notes.push(String::from("no input"));
+            }
+            let solver = get_solver(year, day).unwrap();
+            for param in solver.params() {
+                notes.push(format!("{}={}", param.name, param.default));
+            }
+            if notes.is_empty() {
+                println!("{} day {:02}", year, day);
+            } else {
+                println!("{} day {:02} ({})", year, day, notes.join(", "));
+            }
+        }
     }
 }
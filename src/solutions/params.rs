@@ -0,0 +1,177 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// A value a solution reads with `param`, such as a grid size that differs
+// between the example and the real input
+#[derive(Clone, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+// `name=value` given on the command line
+#[derive(Clone, Debug)]
+pub struct ParamValue {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for ParamValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected `name=value`, found `{s}`"))?;
+        Ok(ParamValue {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+// Values overriding the declared defaults
+pub type Params = HashMap<String, String>;
+
+pub fn to_params(values: &[ParamValue]) -> Params {
+    values
+        .iter()
+        .map(|v| (v.name.clone(), v.value.clone()))
+        .collect()
+}
+
+// Names in `params` that none of the `declared` parameters have
+pub fn unknown<'a>(params: &'a Params, declared: &[Param]) -> Vec<&'a str> {
+    params
+        .keys()
+        .filter(|name| declared.iter().all(|param| param.name != name.as_str()))
+        .map(String::as_str)
+        .collect()
+}
+
+// Every parameter has to be declared by at least one of the days
+pub fn resolve(year: usize, days: &[usize], values: &[ParamValue]) -> Result<Params, String> {
+    let params = to_params(values);
+    let declared = days
+        .iter()
+        .filter_map(|&day| super::get_solver(year, day))
+        .flat_map(|solver| solver.params())
+        .collect::<Vec<_>>();
+    match unknown(&params, &declared)[..] {
+        [] => Ok(params),
+        [name, ..] if declared.is_empty() => Err(format!(
+            "unknown parameter `{name}`, no parameters are declared"
+        )),
+        [name, ..] => Err(format!(
+            "unknown parameter `{name}`, expected one of {}",
+            declared
+                .iter()
+                .map(|param| param.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<HashMap<&'static str, String>> = RefCell::new(HashMap::new());
+}
+
+// Restores the values of the enclosing scope, even when the solution panics
+struct Restore(Option<HashMap<&'static str, String>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take().unwrap_or_default();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+// Runs `f` with the `declared` parameters set to their value in `params`, or
+// their default. Values are per thread, so each thread running a part scopes them.
+pub fn scoped<T>(declared: &[Param], params: &Params, f: impl FnOnce() -> T) -> T {
+    let values = declared
+        .iter()
+        .map(|param| {
+            let value = params.get(param.name).map_or(param.default, String::as_str);
+            (param.name, value.to_string())
+        })
+        .collect();
+    let previous = CURRENT.with(|current| current.replace(values));
+    let _restore = Restore(Some(previous));
+    f()
+}
+
+// Value of one of the `declared` parameters, set by `scoped` or its default
+// outside of it, such as when a tool or a test calls a solution directly
+pub fn get<T>(declared: &[Param], name: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = CURRENT
+        .with(|current| current.borrow().get(name).cloned())
+        .or_else(|| {
+            let param = declared.iter().find(|param| param.name == name)?;
+            Some(param.default.to_string())
+        })
+        .ok_or_else(|| format!("parameter `{name}` isn't declared"))?;
+    value
+        .parse()
+        .map_err(|err| format!("invalid value `{value}` for parameter `{name}`: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    const DECLARED: &[Param] = &[Param {
+        name: "size",
+        default: "71",
+        help: "width and height of the grid",
+    }];
+
+    #[test]
+    fn defaults_and_overrides() {
+        let params = Params::from([(String::from("size"), String::from("7"))]);
+        assert_eq!(
+            scoped(DECLARED, &Params::new(), || get::<usize>(DECLARED, "size")),
+            Ok(71)
+        );
+        assert_eq!(
+            scoped(DECLARED, &params, || get::<usize>(DECLARED, "size")),
+            Ok(7)
+        );
+        assert!(scoped(DECLARED, &params, || get::<usize>(DECLARED, "steps")).is_err());
+        let params = Params::from([(String::from("size"), String::from("big"))]);
+        assert!(scoped(DECLARED, &params, || get::<usize>(DECLARED, "size")).is_err());
+    }
+
+    #[test]
+    fn defaults_outside_of_a_scope() {
+        assert_eq!(get::<usize>(DECLARED, "size"), Ok(71));
+        assert!(get::<usize>(DECLARED, "steps").is_err());
+    }
+
+    #[test]
+    fn restored_after_a_panic() {
+        let outer = Params::from([(String::from("size"), String::from("5"))]);
+        let inner = Params::from([(String::from("size"), String::from("7"))]);
+        scoped(DECLARED, &outer, || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                scoped(DECLARED, &inner, || {
+                    assert_eq!(get::<usize>(DECLARED, "size"), Ok(7));
+                    panic!("solution failed");
+                })
+            }));
+            assert!(result.is_err());
+            assert_eq!(get::<usize>(DECLARED, "size"), Ok(5));
+        });
+        // Nothing is left set once the outer scope ends
+        assert!(get::<usize>(&[], "size").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    // Set by the caller, solvers don't know which day they are
+    pub day: Option<usize>,
+    // 1-based, like an editor shows them
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // `column` is a 0-based char index into `text`
+    pub fn new<M: Display>(text: &str, column: usize, message: M) -> Self {
+        ParseError {
+            day: None,
+            line: 1,
+            column: column + 1,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    // Points at `field`, which must be a slice of `text`
+    pub fn at<M: Display>(text: &str, field: &str, message: M) -> Self {
+        let offset = (field.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .unwrap_or(0);
+        ParseError::new(text, text[..offset].chars().count(), message)
+    }
+
+    // `index` is 0-based, as given by enumerate()
+    pub fn line(mut self, index: usize) -> Self {
+        self.line = index + 1;
+        self
+    }
+
+    pub fn day(mut self, day: usize) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+// `{:#}` adds the offending line with a marker under the column
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if f.alternate() {
+            write!(
+                f,
+                "\n    {}\n    {}^",
+                self.text,
+                " ".repeat(self.column.saturating_sub(1))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn read_lines(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.map_err(|err| ParseError::new("", 0, err).line(i)))
+        .collect()
+}
+
+// Parses every line with `f`, errors are tagged with the line they come from
+pub fn parse_lines<T, F>(reader: impl BufRead, f: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    read_lines(reader)?
+        .iter()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|err| err.line(i)))
+        .collect()
+}
+
+// Parses `field`, a slice of `text`, pointing at it on failure
+pub fn parse_field<T>(text: &str, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|err| ParseError::at(text, field, format!("invalid value `{field}`: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_points_at_the_field() {
+        let text = "p=0,4 v=3,-3";
+        let err = ParseError::at(text, &text[8..], "bad velocity");
+        assert_eq!(err.column, 9);
+        // Columns count chars, not bytes
+        let text = "é,x";
+        assert_eq!(ParseError::at(text, &text[3..], "bad").column, 3);
+        // Anything that isn't a slice of `text` points at the start
+        let other = String::from("x");
+        assert_eq!(ParseError::at(text, &other, "bad").column, 1);
+    }
+
+    #[test]
+    fn parse_lines_tags_lines() {
+        let input = "1 2\n3 4\n5 x\n".as_bytes();
+        let err = parse_lines(input, |line| {
+            line.split(' ')
+                .map(|field| parse_field::<u32>(line, field))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.text, "5 x");
+    }
+
+    #[test]
+    fn caret_rendering() {
+        let text = "1,2,x";
+        let err = parse_field::<u32>(text, &text[4..])
+            .unwrap_err()
+            .line(1)
+            .day(7);
+        assert_eq!(
+            format!("{err}"),
+            "day 07, line 2, column 5: invalid value `x`: invalid digit found in string"
+        );
+        assert_eq!(
+            format!("{err:#}"),
+            "day 07, line 2, column 5: invalid value `x`: invalid digit found in string\n    1,2,x\n        ^"
+        );
+    }
+
+    #[test]
+    fn caret_rendering_without_column() {
+        let err = ParseError {
+            column: 0,
+            ..ParseError::new("", 0, "empty input")
+        };
+        assert_eq!(
+            format!("{err:#}"),
+            "line 1, column 0: empty input\n    \n    ^"
+        );
+    }
+}
@@ -1,48 +1,325 @@
 use std::fmt::Display;
 use std::fs::File;
-use std::io::BufReader;
-use std::time::Instant;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-macro_rules! printResult {
-    ($part:expr, $result:expr, $start:expr ) => {
-        match $result {
-            Ok(res) => println!(
-                "Solution to part {}: {} ({})",
-                $part,
-                res,
-                get_elapsed($start)
-            ),
-            Err(val) => println!("Solution to part {} errored: {}", $part, val),
+use super::params::{self, Param, Params};
+use super::{Answer, ParseError};
+use crate::alloc::{self, Allocs};
+use crate::bench::{self, Samples};
+
+pub struct Example {
+    pub file: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    // Parameters the example is solved with, as `(name, value)`
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+// Why a part has no answer
+#[derive(Clone, Debug)]
+pub enum Failure {
+    Error(String),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Error(msg) => write!(f, "errored: {}", msg),
+            Failure::Panicked(msg) => write!(f, "panicked: {}", msg),
+            Failure::TimedOut(limit) => write!(f, "timed out after {}", get_elapsed(*limit)),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: usize,
+    pub answer: Result<Answer, Failure>,
+    pub elapsed: Duration,
+    // Only measured with the alloc-stats feature
+    pub allocs: Option<Allocs>,
+    pub input_allocs: Option<Allocs>,
+    // Time read_input took, known when the part was solved from the raw input
+    pub input_elapsed: Option<Duration>,
+}
+
+impl PartResult {
+    fn new<T: Into<Answer>>(
+        part: usize,
+        answer: Result<T, String>,
+        start: Instant,
+        allocs: Option<Allocs>,
+    ) -> Self {
+        let elapsed = start.elapsed();
+        PartResult {
+            part,
+            answer: answer.map(Into::into).map_err(Failure::Error),
+            elapsed,
+            allocs,
+            input_allocs: None,
+            input_elapsed: None,
+        }
+    }
+
+    pub fn failed(part: usize, failure: Failure, elapsed: Duration) -> Self {
+        PartResult {
+            part,
+            answer: Err(failure),
+            elapsed,
+            allocs: None,
+            input_allocs: None,
+            input_elapsed: None,
+        }
+    }
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            Ok(res) => match self.allocs {
+                Some(allocs) => write!(
+                    f,
+                    "Solution to part {}: {} ({}, {})",
+                    self.part,
+                    res,
+                    get_elapsed(self.elapsed),
+                    allocs.summary()
+                ),
+                None => write!(
+                    f,
+                    "Solution to part {}: {} ({})",
+                    self.part,
+                    res,
+                    get_elapsed(self.elapsed)
+                ),
+            },
+            Err(failure) => write!(f, "Solution to part {} {}", self.part, failure),
         }
-    };
+    }
 }
 
-pub trait Solver {
-    type Input;
-    type Output1: Display;
-    type Output2: Display;
+pub trait Solver: Sync {
+    type Input: Send + Sync;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input;
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError>;
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String>;
     fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String>;
 
-    fn solve(&self, filename: String, parts: usize) {
-        let file = File::open(filename).expect("input file not found");
-        let input = self.read_input(BufReader::new(&file));
-        if parts & 0x1 > 0 {
-            let start = Instant::now();
-            printResult!(1, self.solve_first(&input), start);
+    fn examples(&self) -> Vec<Example> {
+        vec![]
+    }
+
+    // Values read with `param`, they can be changed with `run --param name=value`
+    fn params(&self) -> Vec<Param> {
+        vec![]
+    }
+
+    fn param<T>(&self, name: &str) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        params::get(&self.params(), name)
+    }
+
+    // Names of the structures of the input `dump` prints in the repl
+    fn dumps(&self) -> Vec<&'static str> {
+        vec![]
+    }
+
+    fn dump(&self, _input: &Self::Input, _name: &str) -> Option<String> {
+        None
+    }
+
+    // A text picture of the input or the answer, shown in the HTML report
+    fn visualize(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    fn bench(
+        &self,
+        contents: &[u8],
+        parts: usize,
+        warmup: usize,
+        runs: usize,
+        params: &Params,
+    ) -> Result<Samples, ParseError> {
+        params::scoped(&self.params(), params, || {
+            let read = || self.read_input(contents);
+            let input = read()?;
+            let mut samples = Samples {
+                parse: bench::sample(warmup, runs, read)?,
+                parts: vec![],
+            };
+            if parts & 0x1 > 0 {
+                let durations = bench::sample_part(warmup, runs, || self.solve_first(&input));
+                samples.parts.push((1, durations));
+            }
+            if parts & 0x2 > 0 {
+                let durations = bench::sample_part(warmup, runs, || self.solve_second(&input));
+                samples.parts.push((2, durations));
+            }
+            Ok(samples)
+        })
+    }
+
+    fn solve_examples(&self, parts: usize) -> bool {
+        self.solve_examples_with(parts, &Params::new())
+    }
+
+    // The example's own parameters take precedence over `params`
+    fn solve_examples_with(&self, parts: usize, params: &Params) -> bool {
+        let declared = self.params();
+        let mut passed = true;
+        for example in self.examples() {
+            println!("Example {}", example.file);
+            let mut params = params.clone();
+            for (name, value) in example.params {
+                params.insert(name.to_string(), value.to_string());
+            }
+            passed &= params::scoped(&declared, &params, || {
+                let file = File::open(example.file).expect("example file not found");
+                let input = match self.read_input(BufReader::new(file)) {
+                    Ok(input) => input,
+                    Err(err) => {
+                        println!("Example input is invalid: {:#}", err);
+                        return false;
+                    }
+                };
+                let mut passed = true;
+                if let (true, Some(expected)) = (parts & 0x1 > 0, example.part1) {
+                    passed &= check_example(1, self.solve_first(&input), expected);
+                }
+                if let (true, Some(expected)) = (parts & 0x2 > 0, example.part2) {
+                    passed &= check_example(2, self.solve_second(&input), expected);
+                }
+                passed
+            });
         }
-        if parts & 0x2 > 0 {
+        passed
+    }
+}
+
+// An input parsed once, the repl runs parts on it as often as it wants and the
+// report draws it
+pub trait Session: Send + Sync {
+    fn solve(&self, part: usize, params: &Params) -> PartResult;
+    fn time(&self, part: usize, runs: usize, params: &Params) -> Result<Vec<Duration>, String>;
+    fn dump(&self, name: &str, params: &Params) -> Option<String>;
+    fn visualize(&self, params: &Params) -> Option<String>;
+}
+
+struct Parsed<'a, S: Solver> {
+    solver: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solver> Session for Parsed<'_, S> {
+    fn solve(&self, part: usize, params: &Params) -> PartResult {
+        params::scoped(&self.solver.params(), params, || {
             let start = Instant::now();
-            printResult!(2, self.solve_second(&input), start)
-        }
+            if part == 1 {
+                let (answer, allocs) = alloc::measure(|| self.solver.solve_first(&self.input));
+                PartResult::new(1, answer, start, allocs)
+            } else {
+                let (answer, allocs) = alloc::measure(|| self.solver.solve_second(&self.input));
+                PartResult::new(2, answer, start, allocs)
+            }
+        })
+    }
+
+    fn time(&self, part: usize, runs: usize, params: &Params) -> Result<Vec<Duration>, String> {
+        params::scoped(&self.solver.params(), params, || {
+            if part == 1 {
+                bench::sample(1, runs, || self.solver.solve_first(&self.input))
+            } else {
+                bench::sample(1, runs, || self.solver.solve_second(&self.input))
+            }
+        })
+    }
+
+    fn dump(&self, name: &str, params: &Params) -> Option<String> {
+        params::scoped(&self.solver.params(), params, || {
+            self.solver.dump(&self.input, name)
+        })
+    }
+
+    fn visualize(&self, params: &Params) -> Option<String> {
+        params::scoped(&self.solver.params(), params, || {
+            self.solver.visualize(&self.input)
+        })
+    }
+}
+
+// Object-safe view over a Solver, so days with different Input/Output
+// types can be picked at runtime.
+pub trait DynSolver {
+    fn params(&self) -> Vec<Param>;
+    fn dumps(&self) -> Vec<&'static str>;
+    fn parse(&self, contents: &[u8], params: &Params) -> Result<Box<dyn Session + '_>, ParseError>;
+    fn bench(
+        &self,
+        contents: &[u8],
+        parts: usize,
+        warmup: usize,
+        runs: usize,
+        params: &Params,
+    ) -> Result<Samples, ParseError>;
+    fn solve_examples(&self, parts: usize, params: &Params) -> bool;
+}
+
+impl<S: Solver> DynSolver for S {
+    fn params(&self) -> Vec<Param> {
+        Solver::params(self)
+    }
+    fn dumps(&self) -> Vec<&'static str> {
+        Solver::dumps(self)
+    }
+    fn parse(&self, contents: &[u8], params: &Params) -> Result<Box<dyn Session + '_>, ParseError> {
+        let input = params::scoped(&Solver::params(self), params, || self.read_input(contents))?;
+        Ok(Box::new(Parsed {
+            solver: self,
+            input,
+        }))
+    }
+    fn bench(
+        &self,
+        contents: &[u8],
+        parts: usize,
+        warmup: usize,
+        runs: usize,
+        params: &Params,
+    ) -> Result<Samples, ParseError> {
+        Solver::bench(self, contents, parts, warmup, runs, params)
+    }
+    fn solve_examples(&self, parts: usize, params: &Params) -> bool {
+        Solver::solve_examples_with(self, parts, params)
     }
 }
 
-fn get_elapsed(start: Instant) -> String {
-    let elapsed = start.elapsed();
+fn check_example<T: Into<Answer>>(part: usize, result: Result<T, String>, expected: &str) -> bool {
+    match result.map(Into::into) {
+        Ok(res) if res == Answer::from(expected) => {
+            println!("Example part {}: {} (ok)", part, res);
+            true
+        }
+        Ok(res) => {
+            println!("Example part {}: {}, expected {}", part, res, expected);
+            false
+        }
+        Err(val) => {
+            println!("Example part {} errored: {}", part, val);
+            false
+        }
+    }
+}
 
+pub fn get_elapsed(elapsed: Duration) -> String {
     let nanos = elapsed.as_nanos();
     let decimals = format!("{nanos}").len();
     match decimals {
@@ -0,0 +1,36 @@
+use super::{read_lines, Example, ParseError, Solver};
+use std::io::BufRead;
+
+pub struct Problem;
+
+impl Solver for Problem {
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_lines(file_reader)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![{{examples}}]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -0,0 +1,40 @@
+use super::{parse_field, parse_lines, Example, ParseError, Solver};
+use std::io::BufRead;
+
+pub struct Problem;
+
+impl Solver for Problem {
+    type Input = Vec<Vec<isize>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            line.split_whitespace()
+                .map(|v| parse_field(line, v))
+                .collect()
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![{{examples}}]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
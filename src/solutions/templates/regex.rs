@@ -0,0 +1,52 @@
+use regex::Regex;
+
+use super::{parse_field, parse_lines, Example, ParseError, Solver};
+use std::io::BufRead;
+
+pub struct Problem;
+
+pub struct Record {
+    a: isize,
+    b: isize,
+}
+
+impl Solver for Problem {
+    type Input = Vec<Record>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let regex = Regex::new(r"(-?\d+)\D+(-?\d+)").unwrap();
+        parse_lines(file_reader, |line| {
+            let res = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::new(line, 0, "line doesn't match the pattern"))?;
+            Ok(Record {
+                a: parse_field(line, &res[1])?,
+                b: parse_field(line, &res[2])?,
+            })
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![{{examples}}]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
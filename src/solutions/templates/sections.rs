@@ -0,0 +1,45 @@
+use super::{read_lines, Example, ParseError, Solver};
+use std::io::BufRead;
+
+pub struct Problem;
+
+impl Solver for Problem {
+    // The lines of each blank line separated section
+    type Input = Vec<Vec<String>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut sections = vec![vec![]];
+        for line in read_lines(file_reader)? {
+            if line.is_empty() {
+                sections.push(vec![]);
+            } else {
+                sections.last_mut().unwrap().push(line);
+            }
+        }
+        Ok(sections)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![{{examples}}]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::{self, Display};
+use std::fs::OpenOptions;
+use std::io::{self, LineWriter, Write};
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "unknown level `{s}`, expected info, debug or trace"
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+// Parsed from `<day>:<level>`, the day can be `all` and the level defaults to debug
+#[derive(Clone, Debug)]
+pub struct Target {
+    day: Option<usize>,
+    level: Level,
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, level) = s.split_once(':').unwrap_or((s, "debug"));
+        let day = match day {
+            "all" => None,
+            day => Some(day.parse().map_err(|_| format!("invalid day `{day}`"))?),
+        };
+        Ok(Target {
+            day,
+            level: level.parse()?,
+        })
+    }
+}
+
+// Written back as `<day>:<level>`, for the runs started by watch
+impl Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "{}:{}", day, self.level),
+            None => write!(f, "all:{}", self.level),
+        }
+    }
+}
+
+struct Config {
+    year: usize,
+    targets: Vec<Target>,
+    output: Mutex<Box<dyn Write + Send>>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// Tracing stays off unless there are targets, messages go to stderr without a
+// file. The file is appended to, so runs started one after the other share it.
+pub fn init(year: usize, targets: Vec<Target>, file: Option<&str>) -> io::Result<()> {
+    if targets.is_empty() {
+        return Ok(());
+    }
+    let output: Box<dyn Write + Send> = match file {
+        Some(path) => {
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            Box::new(LineWriter::new(file))
+        }
+        None => Box::new(io::stderr()),
+    };
+    let config = Config {
+        year,
+        targets,
+        output: Mutex::new(output),
+    };
+    CONFIG.set(config).ok();
+    Ok(())
+}
+
+// Solution modules are named `y<year>::dayNN`, which tells where a message comes from
+fn year_day(module: &str) -> Option<(usize, usize)> {
+    let mut segments = module.rsplit("::");
+    let day = segments.next()?.strip_prefix("day")?.parse().ok()?;
+    let year = segments.next()?.strip_prefix('y')?.parse().ok()?;
+    Some((year, day))
+}
+
+pub fn enabled(module: &str, level: Level) -> bool {
+    let Some(config) = CONFIG.get() else {
+        return false;
+    };
+    let Some((year, day)) = year_day(module) else {
+        return false;
+    };
+    year == config.year
+        && config
+            .targets
+            .iter()
+            .any(|target| target.day.is_none_or(|d| d == day) && level <= target.level)
+}
+
+pub fn write(module: &str, level: Level, args: fmt::Arguments) {
+    let (Some(config), Some((_, day))) = (CONFIG.get(), year_day(module)) else {
+        return;
+    };
+    let mut output = config.output.lock().unwrap();
+    writeln!(output, "[day {:02} {}] {}", day, level, args).ok();
+}
+
+// `trace!(Debug, "format", args...)` from a solution. The arguments are only
+// evaluated when tracing is enabled for the day at that level.
+macro_rules! trace {
+    ($level:ident, $($arg:tt)*) => {
+        if $crate::solutions::trace::enabled(
+            module_path!(),
+            $crate::solutions::trace::Level::$level,
+        ) {
+            $crate::solutions::trace::write(
+                module_path!(),
+                $crate::solutions::trace::Level::$level,
+                format_args!($($arg)*),
+            );
+        }
+    };
+}
+
+pub(crate) use trace;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(s: &str) -> Result<(Option<usize>, Level), String> {
+        s.parse::<Target>().map(|target| (target.day, target.level))
+    }
+
+    #[test]
+    fn targets() {
+        assert_eq!(target("5"), Ok((Some(5), Level::Debug)));
+        assert_eq!(target("5:trace"), Ok((Some(5), Level::Trace)));
+        assert_eq!(target("all:info"), Ok((None, Level::Info)));
+        assert_eq!(target("all"), Ok((None, Level::Debug)));
+        assert!(target("five").is_err());
+        assert!(target("5:loud").is_err());
+        assert!(target("").is_err());
+        for s in ["5:trace", "all:info"] {
+            assert_eq!(s.parse::<Target>().unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn module_year_day() {
+        assert_eq!(
+            year_day("aoc_2024::solutions::y2024::day05"),
+            Some((2024, 5))
+        );
+        assert_eq!(
+            year_day("aoc_2024::solutions::y2024::day24"),
+            Some((2024, 24))
+        );
+        assert_eq!(year_day("aoc_2024::solutions::trace"), None);
+        assert_eq!(year_day("aoc_2024::runner"), None);
+        assert_eq!(year_day("day05"), None);
+    }
+}
@@ -1,6 +1,5 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use super::{parse_field, parse_lines, Example, ParseError, Solver};
+use std::io::BufRead;
 use std::iter::zip;
 
 pub struct Problem;
@@ -10,16 +9,16 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines: Vec<(isize, isize)> = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.split("   ").map(|v| v.parse().unwrap()).collect())
-            .map(|v: Vec<isize>| (v[0], v[1]))
-            .collect();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(file_reader, |line| {
+            let (a, b) = line
+                .split_once("   ")
+                .ok_or_else(|| ParseError::new(line, 0, "expected two numbers"))?;
+            Ok((parse_field(line, a)?, parse_field(line, b)?))
+        })?;
         let list_a = lines.iter().map(|(a, _)| *a).collect();
         let list_b = lines.iter().map(|(_, b)| *b).collect();
-        (list_a, list_b)
+        Ok((list_a, list_b))
     }
 
     fn solve_first(&self, (a, b): &Self::Input) -> Result<Self::Output1, String> {
@@ -50,4 +49,23 @@ impl Solver for Problem {
             })
             .sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/01",
+            part1: Some("11"),
+            part2: Some("31"),
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
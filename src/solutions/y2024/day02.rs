@@ -1,8 +1,7 @@
 use itertools::Itertools;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use super::{parse_field, parse_lines, Example, ParseError, Solver};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -27,12 +26,10 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.split(" ").map(|v| v.parse().unwrap()).collect())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            line.split(" ").map(|v| parse_field(line, v)).collect()
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -60,4 +57,23 @@ impl Solver for Problem {
             })
             .count())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/02",
+            part1: Some("2"),
+            part2: Some("4"),
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
@@ -0,0 +1,97 @@
+use regex::Regex;
+
+use super::{parse_field, parse_lines, Example, ParseError, Solver};
+use std::io::BufRead;
+
+pub struct Problem;
+
+pub enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+// lazy_static::lazy_static! {
+//     static ref LINE_RGX: Regex = Regex::new(r"...").unwrap();
+// }
+impl Solver for Problem {
+    type Input = Vec<Instruction>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let regex = Regex::new(r"(mul|do|don't)\((?:(\d+),(\d+)|)\)").unwrap();
+
+        let lines = parse_lines(file_reader, |line| {
+            let mut instructions = vec![];
+            for res in regex.captures_iter(line) {
+                instructions.push(match &res[1] {
+                    "mul" => match (res.get(2), res.get(3)) {
+                        (Some(a), Some(b)) => Instruction::Mul(
+                            parse_field(line, a.as_str())?,
+                            parse_field(line, b.as_str())?,
+                        ),
+                        // mul() without operands isn't an instruction
+                        _ => continue,
+                    },
+                    "do" => Instruction::Do,
+                    _ => Instruction::Dont,
+                });
+            }
+            Ok(instructions)
+        })?;
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    // 181345830
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        Ok(input
+            .iter()
+            .map(|instr| match instr {
+                Instruction::Mul(a, b) => a * b,
+                _ => 0,
+            })
+            .sum())
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        let mut enabled = true;
+        let mut result = 0;
+        for instr in input {
+            match instr {
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+                Instruction::Mul(a, b) if enabled => result += a * b,
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/03",
+                part1: Some("161"),
+                part2: None,
+                params: &[],
+            },
+            Example {
+                file: "inputs/2024/examples/03-2",
+                part1: None,
+                part2: Some("48"),
+                params: &[],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
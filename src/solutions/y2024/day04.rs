@@ -1,6 +1,5 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use super::{read_lines, Example, ParseError, Solver};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -9,12 +8,11 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(read_lines(file_reader)?
+            .iter()
             .map(|line| line.chars().map(|v| v.to_string()).collect())
-            .collect()
+            .collect())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -38,6 +36,15 @@ impl Solver for Problem {
         }
         Ok(result)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/04",
+            part1: Some("18"),
+            part2: Some("9"),
+            params: &[],
+        }]
+    }
 }
 
 fn find_individual(
@@ -103,3 +110,13 @@ fn find_x_mas(input: &Vec<Vec<String>>, start: (usize, usize)) -> bool {
     return find_individual(input, "MAS", (start.0 + 2, start.1), (-1, 1))
         || find_individual(input, "SAM", (start.0 + 2, start.1), (-1, 1));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -0,0 +1,164 @@
+use itertools::Itertools;
+
+use crate::many_to_many::ManyToMany;
+
+use super::{parse_field, read_lines, Example, ParseError, Solver};
+use std::cmp::Ordering;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub struct Problem;
+
+#[derive(Debug)]
+pub struct Input {
+    rules: ManyToMany<usize, usize>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut part = 0;
+        let mut result: Input = Input {
+            rules: ManyToMany::new(),
+            updates: Vec::new(),
+        };
+        for (i, line) in s.lines().enumerate() {
+            if line == "" {
+                part += 1;
+            } else if part == 0 {
+                let (from, to) = line
+                    .split("|")
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::new(line, 0, "expected a rule like `47|53`").line(i))?;
+                let from_v: usize = parse_field(line, from).map_err(|err| err.line(i))?;
+                let to_v: usize = parse_field(line, to).map_err(|err| err.line(i))?;
+
+                result.rules.insert(from_v, to_v);
+            } else {
+                let update = line
+                    .split(",")
+                    .map(|v| parse_field(line, v))
+                    .collect::<Result<_, _>>()
+                    .map_err(|err| err.line(i))?;
+                result.updates.push(update)
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn update_is_valid(rules: &ManyToMany<usize, usize>, update: &Vec<usize>) -> bool {
+    update.iter().enumerate().all(|(i, v)| {
+        if i == update.len() - 1 {
+            return true;
+        }
+        update[(i + 1)..].iter().all(|other| {
+            let set = rules.outer().get(other);
+            let result = set.is_none() || !set.unwrap().contains(v);
+            return result;
+        })
+    })
+}
+
+fn sort_update(rules: &ManyToMany<usize, usize>, update: &Vec<usize>) -> Vec<usize> {
+    let mut result = update.clone();
+    result.sort_by(|a, b| {
+        if let Some(rule_a) = rules.outer().get(a) {
+            if rule_a.contains(b) {
+                return Ordering::Greater;
+            }
+        }
+        if let Some(rule_b) = rules.outer().get(b) {
+            if rule_b.contains(a) {
+                return Ordering::Less;
+            }
+        }
+        Ordering::Equal
+    });
+    result
+}
+
+impl Solver for Problem {
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_lines(file_reader)?.join("\n").parse()
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        Ok(input
+            .updates
+            .iter()
+            .filter(|v| update_is_valid(&input.rules, &v))
+            .map(|v| v[v.len() / 2])
+            .sum())
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        Ok(input
+            .updates
+            .iter()
+            .filter(|v| !update_is_valid(&input.rules, &v))
+            .map(|v| sort_update(&input.rules, v))
+            .map(|v| v[v.len() / 2])
+            .sum())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/05",
+            part1: Some("143"),
+            part2: Some("123"),
+            params: &[],
+        }]
+    }
+
+    fn dumps(&self) -> Vec<&'static str> {
+        vec!["rules", "updates"]
+    }
+
+    fn dump(&self, input: &Self::Input, name: &str) -> Option<String> {
+        match name {
+            // Every page with the pages that have to come after it
+            "rules" => Some(
+                input
+                    .rules
+                    .outer()
+                    .iter()
+                    .sorted_by_key(|(page, _)| **page)
+                    .map(|(page, after)| {
+                        format!("{page} -> {}", after.iter().sorted().join(","))
+                    })
+                    .join("\n"),
+            ),
+            "updates" => Some(
+                input
+                    .updates
+                    .iter()
+                    .map(|update| {
+                        let status = match update_is_valid(&input.rules, update) {
+                            true => "valid",
+                            false => "invalid",
+                        };
+                        format!("{} ({status})", update.iter().join(","))
+                    })
+                    .join("\n"),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
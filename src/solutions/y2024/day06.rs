@@ -1,11 +1,8 @@
-use itertools::Itertools;
-
 use crate::coordinate::{self, Coordinate, Direction};
 
-use super::Solver;
+use super::{read_lines, trace, Example, ParseError, Solver};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -14,12 +11,16 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
         let mut obstacles = HashSet::new();
         let mut position = Coordinate(0, 0);
 
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
-        let size = Coordinate::from_usize(lines.len(), lines[0].len());
+        let lines = read_lines(file_reader)?;
+        let width = lines
+            .first()
+            .map(|line| line.len())
+            .ok_or_else(|| ParseError::new("", 0, "empty input"))?;
+        let size = Coordinate::from_usize(lines.len(), width);
         for (r, line) in lines.iter().enumerate() {
             for (c, char) in line.chars().enumerate() {
                 match char {
@@ -34,7 +35,7 @@ impl Solver for Problem {
             }
         }
 
-        (obstacles, position, size)
+        Ok((obstacles, position, size))
     }
 
     fn solve_first(
@@ -53,7 +54,9 @@ impl Solver for Problem {
                 new_pos = &position + Coordinate::from(&direction);
             }
 
-            result.insert(new_pos.clone());
+            if new_pos.is_in_bounds(&coordinate::ZERO, size) {
+                result.insert(new_pos.clone());
+            }
             position = new_pos;
         }
 
@@ -88,7 +91,7 @@ impl Solver for Problem {
                         direction.clone(),
                     )
                 {
-                    // println!("({r},{c}), {:?}", new_obstacles);
+                    trace!(Debug, "loop with an obstacle at {:?}", new_pos);
                     result.insert(new_pos.clone());
                 }
             }
@@ -99,6 +102,15 @@ impl Solver for Problem {
 
         Ok(result.len())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/06",
+            part1: Some("41"),
+            part2: Some("6"),
+            params: &[],
+        }]
+    }
 }
 
 fn find_loop(
@@ -128,3 +140,13 @@ fn find_loop(
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -1,8 +1,7 @@
 use itertools::Itertools;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use super::{parse_field, parse_lines, Example, ParseError, Solver};
+use std::io::BufRead;
 use std::str::FromStr;
 
 pub struct Problem;
@@ -80,14 +79,20 @@ impl Equation {
 }
 
 impl FromStr for Equation {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (res, values) = s.split(": ").collect_tuple().unwrap();
+        let (res, values) = s
+            .split(": ")
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(s, 0, "expected `result: values`"))?;
 
         Ok(Equation {
-            result: res.parse().unwrap(),
-            values: values.split(" ").map(|v| v.parse().unwrap()).collect(),
+            result: parse_field(s, res)?,
+            values: values
+                .split(" ")
+                .map(|v| parse_field(s, v))
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -97,13 +102,8 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| line.parse())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -121,4 +121,23 @@ impl Solver for Problem {
             .map(|v| v.result)
             .sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/07",
+            part1: Some("3749"),
+            part2: Some("11387"),
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
@@ -1,11 +1,10 @@
 use itertools::Itertools;
 
-use super::Solver;
+use super::{read_lines, trace, Example, ParseError, Solver};
 use crate::coordinate::{self, get_coordinates_from, Coordinate};
 use crate::many_to_many::ManyToMany;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -14,14 +13,17 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let grid: Vec<Vec<String>> = file_reader
-            .lines()
-            .map(|x| x.unwrap())
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let grid: Vec<Vec<String>> = read_lines(file_reader)?
+            .iter()
             .map(|line| line.chars().map(|v| String::from(v)).collect())
             .collect();
 
-        let size = Coordinate::from_usize(grid.len(), grid[0].len());
+        let width = grid
+            .first()
+            .map(|row| row.len())
+            .ok_or_else(|| ParseError::new("", 0, "empty input"))?;
+        let size = Coordinate::from_usize(grid.len(), width);
         let mut antennas = ManyToMany::new();
         for (coord, cell) in get_coordinates_from(&grid) {
             if cell.len() == 0 {
@@ -34,7 +36,7 @@ impl Solver for Problem {
             antennas.insert(char, coord);
         }
 
-        (size, antennas)
+        Ok((size, antennas))
     }
 
     fn solve_first(&self, (size, antennas): &Self::Input) -> Result<Self::Output1, String> {
@@ -80,17 +82,40 @@ impl Solver for Problem {
             }
         }
 
-        // for r in 0..size.0 {
-        //     for c in 0..size.1 {
-        //         if antinodes.contains(&Coordinate(r, c)) {
-        //             print!("#");
-        //         } else {
-        //             print!(".");
-        //         }
-        //     }
-        //     println!();
-        // }
+        trace!(
+            Debug,
+            "antinodes\n{}",
+            (0..size.0)
+                .map(|r| {
+                    (0..size.1)
+                        .map(|c| match antinodes.contains(&Coordinate(r, c)) {
+                            true => '#',
+                            false => '.',
+                        })
+                        .collect::<String>()
+                })
+                .join("\n")
+        );
 
         Ok(antinodes.len())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/08",
+            part1: Some("14"),
+            part2: Some("34"),
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
@@ -1,9 +1,8 @@
 use itertools::Itertools;
 
-use super::Solver;
+use super::{parse_lines, Example, ParseError, Solver};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -12,16 +11,18 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .flat_map(|line| {
-                line.chars()
-                    .map(|c| String::from(c).parse::<usize>().unwrap())
-                    .collect_vec()
-            })
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(file_reader, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(c, char)| {
+                    char.to_digit(10)
+                        .map(|v| v as usize)
+                        .ok_or_else(|| ParseError::new(line, c, format!("expected a digit, found `{char}`")))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(lines.concat())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -109,9 +110,28 @@ impl Solver for Problem {
             })
             .fold(0, |a, b| a + b))
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/09",
+            part1: Some("1928"),
+            part2: Some("2858"),
+            params: &[],
+        }]
+    }
 }
 
 struct Block {
     position: usize,
     length: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -1,10 +1,7 @@
-use itertools::Itertools;
-
-use super::Solver;
+use super::{parse_lines, Example, ParseError, Solver};
 use crate::coordinate::Coordinate;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -13,16 +10,17 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| {
-                line.chars()
-                    .map(|v| String::from(v).parse().unwrap())
-                    .collect_vec()
-            })
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(c, char)| {
+                    char.to_digit(10)
+                        .map(|v| v as isize)
+                        .ok_or_else(|| ParseError::new(line, c, format!("expected a digit, found `{char}`")))
+                })
+                .collect()
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -51,6 +49,15 @@ impl Solver for Problem {
         }
         Ok(result)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/10",
+            part1: Some("36"),
+            part2: Some("81"),
+            params: &[],
+        }]
+    }
 }
 
 fn count_trails(
@@ -81,3 +88,13 @@ fn count_trails(
         })
         .fold(0, |a, b| a + b)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
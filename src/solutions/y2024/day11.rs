@@ -1,9 +1,6 @@
-use itertools::Itertools;
-
-use super::Solver;
+use super::{parse_field, parse_lines, Example, Param, ParseError, Solver};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -12,26 +9,52 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .flat_map(|line| line.split(" ").map(|v| v.parse().unwrap()).collect_vec())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(file_reader, |line| {
+            line.split(" ")
+                .map(|v| parse_field(line, v))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(lines.concat())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
-        Ok((0..25).fold(input.clone(), |acc, _| evolve(acc)).len())
+        Ok((0..self.param("blinks1")?).fold(input.clone(), |acc, _| evolve(acc)).len())
     }
 
     fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        let blinks = self.param("blinks2")?;
         let mut rock_results: HashMap<(isize, isize), isize> = HashMap::new();
 
         Ok(input
             .iter()
-            .map(|v| find_result(*v, 75, &mut rock_results))
+            .map(|v| find_result(*v, blinks, &mut rock_results))
             .sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/11",
+            part1: Some("55312"),
+            part2: None,
+            params: &[],
+        }]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param {
+                name: "blinks1",
+                default: "25",
+                help: "times the stones blink in the first part",
+            },
+            Param {
+                name: "blinks2",
+                default: "75",
+                help: "times the stones blink in the second part",
+            },
+        ]
+    }
 }
 
 fn find_result(
@@ -72,3 +95,13 @@ fn evolve_single(value: isize) -> Vec<isize> {
 fn evolve(value: Vec<isize>) -> Vec<isize> {
     value.into_iter().flat_map(evolve_single).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -2,10 +2,9 @@ use itertools::Itertools;
 
 use crate::coordinate::{get_coordinates_from, Coordinate, Direction, CARDINALS};
 
-use super::Solver;
+use super::{read_lines, trace, Example, ParseError, Solver};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -14,12 +13,11 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(read_lines(file_reader)?
+            .iter()
             .map(|line| line.chars().collect_vec())
-            .collect()
+            .collect())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -62,7 +60,7 @@ impl Solver for Problem {
                 )
             })
             .collect_vec();
-        // println!("{:?}", areas_zipped);
+        trace!(Debug, "(plant, area, perimeter) {:?}", areas_zipped);
 
         Ok(areas_zipped
             .into_iter()
@@ -147,13 +145,30 @@ impl Solver for Problem {
             .iter()
             .map(|(id, area)| (id_to_char.get(id).unwrap(), area, sides.get(id).unwrap()))
             .collect_vec();
-        // println!("{:?}", areas_zipped);
+        trace!(Debug, "(plant, area, sides) {:?}", areas_zipped);
 
         Ok(areas_zipped
             .into_iter()
             .map(|(_, area, sides)| area * sides)
             .sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/12",
+                part1: Some("140"),
+                part2: Some("80"),
+                params: &[],
+            },
+            Example {
+                file: "inputs/2024/examples/12-2",
+                part1: Some("1930"),
+                part2: Some("1206"),
+                params: &[],
+            },
+        ]
+    }
 }
 
 fn fill_id(
@@ -175,3 +190,13 @@ fn fill_id(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -2,9 +2,8 @@ use itertools::Itertools;
 
 use crate::coordinate::Coordinate;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use super::{parse_field, read_lines, Example, ParseError, Solver};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -14,11 +13,19 @@ pub struct Machine {
     prize: Coordinate,
 }
 
-fn parse_coordinates(line: &str) -> Coordinate {
-    let (_, data) = line.split(": ").collect_tuple().unwrap();
-    let (x, y) = data.split(", ").collect_tuple().unwrap();
+fn parse_coordinates(line: &str) -> Result<Coordinate, ParseError> {
+    let (_, data) = line
+        .split(": ")
+        .collect_tuple()
+        .ok_or_else(|| ParseError::new(line, 0, "expected `Label: X.., Y..`"))?;
+    let (x, y) = data
+        .split(", ")
+        .collect_tuple()
+        .ok_or_else(|| ParseError::at(line, data, "expected `X.., Y..`"))?;
+    // Skips the `X+` / `Y=` prefix
+    let value = |field: &str| parse_field(line, field.get(2..).unwrap_or(field));
 
-    Coordinate(x[2..].parse().unwrap(), y[2..].parse().unwrap())
+    Ok(Coordinate(value(x)?, value(y)?))
 }
 
 impl Machine {
@@ -50,18 +57,19 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let line = |i: usize| lines.get(i).map(String::as_str).unwrap_or("");
         let mut result = Vec::new();
 
         for i in (0..lines.len()).step_by(4) {
             result.push(Machine {
-                a: parse_coordinates(&lines[i]),
-                b: parse_coordinates(&lines[i + 1]),
-                prize: parse_coordinates(&lines[i + 2]),
+                a: parse_coordinates(line(i)).map_err(|err| err.line(i))?,
+                b: parse_coordinates(line(i + 1)).map_err(|err| err.line(i + 1))?,
+                prize: parse_coordinates(line(i + 2)).map_err(|err| err.line(i + 2))?,
             });
         }
-        result
+        Ok(result)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -79,4 +87,23 @@ impl Solver for Problem {
             .map(|v| v.get_price())
             .sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/13",
+            part1: Some("480"),
+            part2: None,
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
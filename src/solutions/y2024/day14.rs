@@ -0,0 +1,224 @@
+#![allow(unused)]
+use itertools::Itertools;
+
+use crate::coordinate::Coordinate;
+
+use super::{parse_field, parse_lines, trace, Example, Param, ParseError, Solver};
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub struct Problem;
+
+#[derive(Clone, Debug)]
+pub struct Robot {
+    position: Coordinate,
+    velocity: Coordinate,
+}
+
+// `field` is the `p=x,y` / `v=x,y` part of `line`
+fn parse_coordinate(line: &str, field: &str) -> Result<Coordinate, ParseError> {
+    let (x, y) = field
+        .get(2..)
+        .and_then(|str| str.split(",").collect_tuple())
+        .ok_or_else(|| ParseError::at(line, field, "expected `x,y`"))?;
+    Ok(Coordinate(parse_field(line, x)?, parse_field(line, y)?))
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (p, v) = s
+            .split(" ")
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(s, 0, "expected `p=x,y v=x,y`"))?;
+        Ok(Robot {
+            position: parse_coordinate(s, p)?,
+            velocity: parse_coordinate(s, v)?,
+        })
+    }
+}
+
+pub fn gcd(mut n: usize, mut m: usize) -> usize {
+    assert!(n != 0 && m != 0);
+    while m != 0 {
+        if m < n {
+            std::mem::swap(&mut m, &mut n);
+        }
+        m %= n;
+    }
+    n
+}
+pub fn period(a: usize, m: usize) -> usize {
+    if a == 0 {
+        return 1;
+    }
+    let d = gcd(a, m);
+    if d == 1 {
+        m
+    } else {
+        m / d
+    }
+}
+
+impl Robot {
+    pub fn step(&mut self, steps: isize, bounds: &Coordinate) {
+        self.position.0 = (self.position.0 + self.velocity.0 * steps) % bounds.0;
+        if self.position.0 < 0 {
+            self.position.0 += bounds.0;
+        }
+        self.position.1 = (self.position.1 + self.velocity.1 * steps) % bounds.1;
+        if self.position.1 < 0 {
+            self.position.1 += bounds.1;
+        }
+    }
+    fn get_quadrant(&self, bounds: &Coordinate) -> Option<Coordinate> {
+        let half = bounds / 2;
+        if self.position.0 == half.0 || self.position.1 == half.1 {
+            None
+        } else {
+            let x = if self.position.0 < half.0 { 0 } else { 1 };
+            let y = if self.position.1 < half.1 { 0 } else { 1 };
+
+            Some(Coordinate(x, y))
+        }
+    }
+    fn period(&self, bounds: &Coordinate) -> usize {
+        period(self.velocity.0.unsigned_abs(), bounds.0 as usize)
+            * period(self.velocity.1.unsigned_abs(), bounds.1 as usize)
+    }
+}
+
+// Product of the number of robots in each quadrant
+fn safety_factor(robots: &[Robot], bounds: &Coordinate) -> usize {
+    robots
+        .iter()
+        .filter_map(|robot| robot.get_quadrant(bounds))
+        .counts()
+        .values()
+        .product()
+}
+
+// Number of robots on each tile, `empty` where there are none
+fn robot_map(robots: &[Robot], bounds: &Coordinate, empty: char) -> String {
+    let counts = robots.iter().counts_by(|robot| robot.position.clone());
+    (0..bounds.1)
+        .map(|y| {
+            (0..bounds.0)
+                .map(|x| {
+                    counts
+                        .get(&Coordinate(x, y))
+                        .map_or(empty.to_string(), |count| count.to_string())
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+impl Solver for Problem {
+    type Input = Vec<Robot>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| line.parse())
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        let bounds: Coordinate = self.param("bounds")?;
+        let steps: isize = self.param("steps")?;
+        let mut robots = input.iter().cloned().collect_vec();
+
+        for robot in robots.iter_mut() {
+            robot.step(steps, &bounds);
+        }
+
+        trace!(
+            Debug,
+            "after {} steps\n{}",
+            steps,
+            robot_map(&robots, &bounds, '0')
+        );
+
+        Ok(safety_factor(&robots, &bounds))
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        let bounds: Coordinate = self.param("bounds")?;
+        let mut robots = input.iter().cloned().collect_vec();
+
+        for robot in robots.iter() {
+            trace!(Trace, "period {}", robot.period(&bounds));
+        }
+        // See that the period is 10403 for all of them (which is W*H)
+        // Means that it repeats every 10403 steps.
+
+        for i in 0..bounds.0 * bounds.1 {
+            for robot in robots.iter_mut() {
+                robot.step(1, &bounds);
+            }
+
+            // See that every now and then bots arrange into something blurry
+            // note the period is 103 for a horizontal blur and 101 for a vertical blur
+            // Then just print those specifics to reduce the noise
+            if i % 103 == 0 || i % 101 == 45 {
+                trace!(Debug, "after {} steps\n{}", i + 1, robot_map(&robots, &bounds, ' '));
+            }
+        }
+
+        // Visually see that it's when i=7519, meaning 7520 steps.
+        Ok(7519 + 1)
+    }
+
+    // The robots after the step with the lowest safety factor within a period:
+    // the tree packs most of them into a single quadrant
+    fn visualize(&self, input: &Self::Input) -> Option<String> {
+        let bounds: Coordinate = self.param("bounds").ok()?;
+        let mut robots = input.clone();
+        let mut best = (safety_factor(&robots, &bounds), robots.clone());
+        for _ in 1..bounds.0 * bounds.1 {
+            for robot in robots.iter_mut() {
+                robot.step(1, &bounds);
+            }
+            let factor = safety_factor(&robots, &bounds);
+            if factor < best.0 {
+                best = (factor, robots.clone());
+            }
+        }
+        Some(robot_map(&best.1, &bounds, '.'))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/14",
+            part1: Some("12"),
+            part2: None,
+            params: &[("bounds", "11,7")],
+        }]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param {
+                name: "bounds",
+                default: "101,103",
+                help: "size of the area as `width,height`",
+            },
+            Param {
+                name: "steps",
+                default: "100",
+                help: "seconds the robots move for in the first part",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(1));
+    }
+}
@@ -3,10 +3,9 @@ use itertools::Itertools;
 
 use crate::coordinate::{Coordinate, Direction, ZERO};
 
-use super::Solver;
+use super::{read_lines, trace, Example, ParseError, Solver};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::iter;
 
 pub struct Problem;
@@ -123,8 +122,9 @@ impl Warehouse {
         }
     }
 
-    fn print(&self, wide: bool) {
-        let mut grid = iter::repeat_with(|| vec![' '; 100]).take(100).collect_vec();
+    // The warehouse as the puzzle draws it, for the traces
+    fn render(&self, wide: bool) -> String {
+        let mut grid = iter::repeat_with(|| vec!['.'; 100]).take(100).collect_vec();
         grid[self.robot.0 as usize][self.robot.1 as usize] = '@';
 
         let mut max = ZERO.clone();
@@ -147,13 +147,10 @@ impl Warehouse {
             }
         }
 
-        println!();
-        for r in 0..=max.0 {
-            for c in 0..=max.1 {
-                print!("{}", grid[r as usize][c as usize]);
-            }
-            println!();
-        }
+        grid[..=max.0 as usize]
+            .iter()
+            .map(|row| row[..=max.1 as usize].iter().collect::<String>())
+            .join("\n")
     }
 }
 
@@ -162,8 +159,8 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
         let mut warehouse = Warehouse {
             map: HashMap::new(),
             robot: ZERO,
@@ -193,26 +190,26 @@ impl Solver for Problem {
                     }
                 }
             } else {
-                directions.push(
-                    line.chars()
-                        .map(|char| Direction::from_arrow_char(char).expect("Unknown direction"))
-                        .collect_vec(),
-                );
+                for (c, char) in line.chars().enumerate() {
+                    let direction = Direction::from_arrow_char(char).ok_or_else(|| {
+                        ParseError::new(line, c, format!("unknown direction `{char}`")).line(r)
+                    })?;
+                    directions.push(direction);
+                }
             }
         }
 
-        (warehouse, directions.concat())
+        Ok((warehouse, directions))
     }
 
     fn solve_first(&self, (warehouse, directions): &Self::Input) -> Result<Self::Output1, String> {
         let mut warehouse = warehouse.clone();
 
         for dir in directions {
-            // warehouse.print();
             warehouse.move_robot(dir);
+            trace!(Trace, "{:?}\n{}", dir, warehouse.render(false));
         }
-
-        // warehouse.print();
+        trace!(Debug, "final warehouse\n{}", warehouse.render(false));
 
         Ok(warehouse.gps_sum())
     }
@@ -222,12 +219,37 @@ impl Solver for Problem {
 
         for dir in directions {
             warehouse.move_robot_wide(dir);
-            // print!("\x1Bc");
-            // warehouse.print(true);
-            // println!("{:?}", dir);
-            // sleep(time::Duration::from_millis(6));
+            trace!(Trace, "{:?}\n{}", dir, warehouse.render(true));
         }
+        trace!(Debug, "final warehouse\n{}", warehouse.render(true));
 
         Ok(warehouse.gps_sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/15",
+                part1: Some("2028"),
+                part2: None,
+                params: &[],
+            },
+            Example {
+                file: "inputs/2024/examples/15-2",
+                part1: None,
+                part2: Some("618"),
+                params: &[],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
@@ -3,10 +3,9 @@ use pathfinding::prelude::{astar, astar_bag_collect};
 
 use crate::coordinate::{Coordinate, Direction};
 
-use super::Solver;
+use super::{read_lines, Example, ParseError, Solver};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -68,12 +67,11 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(read_lines(file_reader)?
+            .iter()
             .map(|line| line.chars().collect_vec())
-            .collect()
+            .collect())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -118,6 +116,23 @@ impl Solver for Problem {
 
         Ok(coordinates.len())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/16",
+                part1: Some("7036"),
+                part2: Some("45"),
+                params: &[],
+            },
+            Example {
+                file: "inputs/2024/examples/16-2",
+                part1: Some("11048"),
+                part2: Some("64"),
+                params: &[],
+            },
+        ]
+    }
 }
 
 /*
@@ -149,3 +164,13 @@ fn main() {
     let hmap: HashSet<StateSet> = HashSet::new();
 }
  */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
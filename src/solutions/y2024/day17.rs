@@ -1,8 +1,7 @@
 use itertools::Itertools;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use super::{parse_field, read_lines, trace, Example, ParseError, Solver};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -52,32 +51,40 @@ impl Solver for Problem {
     type Output1 = String;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
-        let reg_a = lines[0][12..].parse().unwrap();
-        let reg_b = lines[1][12..].parse().unwrap();
-        let reg_c = lines[2][12..].parse().unwrap();
-        let program = lines[4][9..]
-            .split(",")
-            .map(|v| v.parse().unwrap())
-            .collect_vec();
-
-        Machine {
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let line = |i: usize| lines.get(i).map(String::as_str).unwrap_or("");
+        let register = |i: usize, name: &str| -> Result<usize, ParseError> {
+            let text = line(i);
+            text.strip_prefix(&format!("Register {name}: "))
+                .ok_or_else(|| ParseError::new(text, 0, format!("expected `Register {name}: <value>`")))
+                .and_then(|value| parse_field(text, value))
+                .map_err(|err| err.line(i))
+        };
+        let registers = [register(0, "A")?, register(1, "B")?, register(2, "C")?];
+        let text = line(4);
+        let program = text
+            .strip_prefix("Program: ")
+            .ok_or_else(|| ParseError::new(text, 0, "expected `Program: <values>`"))
+            .and_then(|values| values.split(",").map(|v| parse_field(text, v)).collect())
+            .map_err(|err| err.line(4))?;
+
+        Ok(Machine {
             output: vec![],
             pc: 0,
             program,
-            registers: [reg_a, reg_b, reg_c],
-        }
+            registers,
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
         let mut machine = input.clone();
 
-        // println!("{:?}", machine);
+        trace!(Debug, "{:?}", machine);
         while machine.step() {
-            // println!("{:?}", machine);
+            trace!(Trace, "{:?}", machine);
         }
-        // println!("{:?}", machine);
+        trace!(Debug, "{:?}", machine);
 
         Ok(machine.output.iter().join(","))
     }
@@ -96,6 +103,23 @@ impl Solver for Problem {
 
         Ok(result.unwrap())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/17",
+                part1: Some("4,6,3,5,6,3,5,2,1,0"),
+                part2: None,
+                params: &[],
+            },
+            Example {
+                file: "inputs/2024/examples/17-2",
+                part1: None,
+                part2: Some("117440"),
+                params: &[],
+            },
+        ]
+    }
 }
 
 fn simulate_dontjump(dontjump: &Machine) -> usize {
@@ -139,3 +163,13 @@ fn find_output(a: usize, dontjump: &mut Machine, output: &[usize]) -> Option<usi
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
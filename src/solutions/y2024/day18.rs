@@ -0,0 +1,147 @@
+use itertools::Itertools;
+use pathfinding::prelude::astar;
+
+use crate::coordinate::{Coordinate, ZERO};
+
+use super::{parse_field, parse_lines, Example, Param, ParseError, Solver};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Problem;
+
+fn shortest_path(corrupted: &HashSet<Coordinate>, bounds: &Coordinate) -> Option<Vec<Coordinate>> {
+    let end = Coordinate(bounds.0 - 1, bounds.1 - 1);
+    astar(
+        &ZERO,
+        |origin| {
+            origin
+                .cardinals()
+                .iter()
+                .filter(|c| !corrupted.contains(c) && c.is_in_bounds(&ZERO, bounds))
+                .cloned()
+                .map(|v| (v, 1))
+                .collect_vec()
+        },
+        |origin| origin.euclidean_distance(&end),
+        |origin| origin == &end,
+    )
+    .map(|(path, _)| path)
+}
+
+impl Solver for Problem {
+    type Input = Vec<Coordinate>;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            let (x, y) = line
+                .split_once(",")
+                .ok_or_else(|| ParseError::new(line, 0, "expected `x,y`"))?;
+            Ok(Coordinate::from_usize(
+                parse_field(line, x)?,
+                parse_field(line, y)?,
+            ))
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        let bounds: Coordinate = self.param("bounds")?;
+        let corrupted = input.iter().take(self.param("bytes")?).cloned().collect();
+
+        let path = shortest_path(&corrupted, &bounds).ok_or("there is no way out")?;
+        Ok(path.len() - 1)
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        let bounds: Coordinate = self.param("bounds")?;
+        let mut corrupted: HashSet<Coordinate> =
+            input.iter().take(self.param("bytes")?).cloned().collect();
+
+        'outer: while let Some(path) = shortest_path(&corrupted, &bounds) {
+            let coordinates: HashSet<&Coordinate> = path.iter().collect();
+            let range = (corrupted.len() - 1)..input.len();
+            for i in range {
+                corrupted.insert(input[i].clone());
+                if coordinates.contains(&input[i]) {
+                    continue 'outer;
+                }
+            }
+        }
+
+        let corruption = input[corrupted.len() - 1].clone();
+        Ok(format!("{},{}", corruption.0, corruption.1))
+    }
+
+    // The corrupted memory after the first bytes fell, with the shortest path through it
+    fn visualize(&self, input: &Self::Input) -> Option<String> {
+        let bounds: Coordinate = self.param("bounds").ok()?;
+        let corrupted = input.iter().take(self.param("bytes").ok()?).cloned().collect();
+        let path: HashSet<Coordinate> = shortest_path(&corrupted, &bounds)
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        Some(
+            (0..bounds.1)
+                .map(|y| {
+                    (0..bounds.0)
+                        .map(|x| match Coordinate(x, y) {
+                            c if corrupted.contains(&c) => '#',
+                            c if path.contains(&c) => 'O',
+                            _ => '.',
+                        })
+                        .collect::<String>()
+                })
+                .join("\n"),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/18",
+            part1: Some("22"),
+            part2: Some("6,1"),
+            params: &[("bounds", "7,7"), ("bytes", "12")],
+        }]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param {
+                name: "bounds",
+                default: "71,71",
+                help: "size of the memory space as `width,height`",
+            },
+            Param {
+                name: "bytes",
+                default: "1024",
+                help: "number of bytes fallen before the first part",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{params, Params};
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+
+    // Outside of a run the declared defaults apply, a 71 by 71 space here
+    #[test]
+    fn default_params() {
+        let file = std::fs::File::open("inputs/2024/examples/18").unwrap();
+        let input = Problem.read_input(std::io::BufReader::new(file)).unwrap();
+        let params = Params::from([
+            (String::from("bounds"), String::from("71,71")),
+            (String::from("bytes"), String::from("1024")),
+        ]);
+        let scoped = params::scoped(&Problem.params(), &params, || Problem.solve_first(&input));
+        assert_eq!(Problem.solve_first(&input), scoped);
+        assert!(Problem.solve_first(&input).is_ok());
+    }
+}
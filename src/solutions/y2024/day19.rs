@@ -1,9 +1,8 @@
 use itertools::Itertools;
 
-use super::Solver;
+use super::{read_lines, Example, ParseError, Solver};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::iter::zip;
 
 pub struct Problem;
@@ -62,19 +61,22 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
 
-        let patterns = lines[0]
+        let patterns = lines
+            .first()
+            .ok_or_else(|| ParseError::new("", 0, "empty input"))?
             .split(", ")
             .map(|p| p.chars().collect_vec())
             .collect_vec();
-        let designs = (&lines[2..])
+        let designs = lines
             .iter()
+            .skip(2)
             .map(|line| line.chars().collect_vec())
             .collect_vec();
 
-        (patterns, designs)
+        Ok((patterns, designs))
     }
 
     fn solve_first(&self, (patterns, designs): &Self::Input) -> Result<Self::Output1, String> {
@@ -92,4 +94,23 @@ impl Solver for Problem {
             .map(|d| count_designs(&d[..], patterns, &mut cache))
             .sum())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/19",
+            part1: Some("6"),
+            part2: Some("16"),
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
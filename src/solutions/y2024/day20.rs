@@ -1,10 +1,9 @@
-use super::Solver;
+use super::{read_lines, trace, Example, Param, ParseError, Solver};
 use crate::coordinate::Coordinate;
 use itertools::Itertools;
 use priority_queue::PriorityQueue;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -76,6 +75,20 @@ impl State {
     }
 }
 
+// The grid with `mark` drawn over the tiles it returns something for
+fn draw(input: &[Vec<char>], mark: impl Fn(&Coordinate) -> Option<String>) -> String {
+    input
+        .iter()
+        .enumerate()
+        .map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(c, tile)| mark(&Coordinate::from_usize(r, c)).unwrap_or(tile.to_string()))
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
 fn solve(input: &Vec<Vec<char>>, threshold: isize, cheats: usize) -> usize {
     let start = find_char(input, 'S').unwrap();
     let end = find_char(input, 'E').unwrap();
@@ -100,16 +113,11 @@ fn solve(input: &Vec<Vec<char>>, threshold: isize, cheats: usize) -> usize {
         }
     }
 
-    // for r in 0..input.len() {
-    //     for c in 0..input[r].len() {
-    //         if let Some(d) = distances.get(&Coordinate::from_usize(r, c)) {
-    //             print!("{d}")
-    //         } else {
-    //             print!("{}", input[r][c])
-    //         }
-    //     }
-    //     println!("")
-    // }
+    trace!(
+        Trace,
+        "distances to the end\n{}",
+        draw(input, |c| distances.get(c).map(|d| d.to_string()))
+    );
 
     let mut queue = PriorityQueue::new();
     let initial = State::new(&start);
@@ -133,40 +141,38 @@ fn solve(input: &Vec<Vec<char>>, threshold: isize, cheats: usize) -> usize {
         }
         visited.insert(state.position.clone());
 
-        // println!("");
-        // println!("{:?}", state);
+        trace!(Trace, "{:?}", state);
         for successor in state.successors(input, cheats) {
-            // println!("  {:?}", successor);
+            trace!(Trace, "  {:?}", successor);
             queue.push(successor, inv_time - 1);
         }
     }
 
-    // for (c, d) in result.iter() {
-    //     println!("{d} {:?}", c);
-    // }
-    // let cheats: HashMap<Coordinate, char> = result
-    //     .keys()
-    //     .cloned()
-    //     .flat_map(|(first, second)| vec![(first, '1'), (second, '2')])
-    //     .collect();
-    // for r in 0..input.len() {
-    //     for c in 0..input[r].len() {
-    //         if let Some(d) = cheats.get(&Coordinate::from_usize(r, c)) {
-    //             print!("{d}")
-    //         } else {
-    //             print!("{}", input[r][c])
-    //         }
-    //     }
-    //     println!("")
-    // }
-    // let mut counts: HashMap<isize, usize> = HashMap::new();
-    // for (_, d) in result.iter() {
-    //     let count = counts.get(d).unwrap_or(&0);
-    //     counts.insert(*d, count + 1);
-    // }
-    // for (ps, c) in counts {
-    //     println!("{ps} {c}");
-    // }
+    // Everything below is only computed when traced
+    trace!(
+        Trace,
+        "cheats\n{}",
+        result.iter().map(|(c, d)| format!("{d} {:?}", c)).join("\n")
+    );
+    trace!(Debug, "cheat entries and exits\n{}", {
+        let entries_exits: HashMap<Coordinate, char> = result
+            .keys()
+            .cloned()
+            .flat_map(|(first, second)| vec![(first, '1'), (second, '2')])
+            .collect();
+        draw(input, |c| entries_exits.get(c).map(|mark| mark.to_string()))
+    });
+    trace!(
+        Debug,
+        "savings\n{}",
+        result
+            .values()
+            .counts()
+            .into_iter()
+            .sorted()
+            .map(|(saved, count)| format!("{count} cheats save {saved} picoseconds"))
+            .join("\n")
+    );
 
     result.len()
 }
@@ -176,20 +182,50 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(read_lines(file_reader)?
+            .iter()
             .map(|line| line.chars().collect_vec())
-            .collect()
+            .collect())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
-        Ok(solve(input, 100, 2))
+        Ok(solve(input, self.param("threshold")?, 2))
     }
 
     fn solve_second(&self, input: &Self::Input) -> Result<Self::Output1, String> {
-        Ok(solve(input, 100, 20))
+        Ok(solve(input, self.param("threshold")?, 20))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/20",
+                part1: Some("44"),
+                part2: None,
+                params: &[("threshold", "1")],
+            },
+            Example {
+                file: "inputs/2024/examples/20",
+                part1: Some("1"),
+                part2: Some("86"),
+                params: &[("threshold", "64")],
+            },
+            Example {
+                file: "inputs/2024/examples/20",
+                part1: Some("0"),
+                part2: Some("3"),
+                params: &[("threshold", "76")],
+            },
+        ]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param {
+            name: "threshold",
+            default: "100",
+            help: "picoseconds a cheat has to save to be counted",
+        }]
     }
 }
 
@@ -204,3 +240,13 @@ fn find_char(input: &Vec<Vec<char>>, needle: char) -> Option<Coordinate> {
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
+}
@@ -3,11 +3,10 @@ use pathfinding::prelude::dijkstra;
 
 use crate::coordinate::{Coordinate, Direction};
 
-use super::Solver;
+use super::{parse_field, parse_lines, trace, Example, ParseError, Solver};
 use core::panic;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::str::FromStr;
 
 pub struct Problem;
@@ -18,11 +17,14 @@ pub struct Code {
 }
 
 impl FromStr for Code {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let code = s.chars().collect_vec();
-        let value = s[0..(s.len() - 1).max(1)].parse().unwrap();
+        let digits = s
+            .strip_suffix('A')
+            .ok_or_else(|| ParseError::new(s, code.len(), "expected a code ending in `A`"))?;
+        let value = parse_field(s, digits)?;
         return Ok(Code { value, code });
     }
 }
@@ -179,16 +181,21 @@ fn get_length(
         Coordinate(0, 0)
     };
     let paths = get_paths(&from, &to, &forbidden);
-    // for _ in 1..depth {
-    //     print!(" ");
-    // }
-    // println!("paths {:?}->{:?} {:?}", from, to, paths);
+    trace!(
+        Trace,
+        "{:indent$}paths {:?}->{:?} {:?}",
+        "",
+        from,
+        to,
+        paths,
+        indent = depth - 1
+    );
 
     let result = if depth == max_depth {
         paths.iter().map(|p| p.len()).min().unwrap() + 1
     } else {
         if depth == max_depth - 2 {
-            println!("New call")
+            trace!(Debug, "New call")
         }
         paths
             .iter()
@@ -209,7 +216,7 @@ fn get_length(
                     })
                     .sum();
                 if depth == max_depth - 2 {
-                    println!("{:?} {result}", p);
+                    trace!(Debug, "{:?} {result}", p);
                 }
                 result
             })
@@ -233,19 +240,6 @@ fn get_code_length(
         .tuple_windows()
         .map(|(prev, next)| get_length(&prev, &next, 1, depth, cache))
         .sum::<usize>()
-
-    // let mut position = Coordinate(3, 2);
-    // let forbidden = Coordinate(3, 0);
-    // // let confirm = Coordinate(0, 2);
-
-    // for c in code {
-    //     let dest = char_to_coordinate(*c);
-    //     let paths = get_paths(&position, &dest, &forbidden);
-    //     println!("{:?}, {:?} -> {:?}", paths, position, dest);
-    //     position = dest;
-    // }
-
-    // todo!()
 }
 
 fn direction_to_pos(dir: &Direction) -> Coordinate {
@@ -425,9 +419,6 @@ impl State {
                 }
             })
             .collect_vec();
-        // if result.len() > 3 {
-        //     println!("{}", result.len());
-        // }
         return result;
     }
 
@@ -470,13 +461,8 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| line.parse())
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -484,7 +470,7 @@ impl Solver for Problem {
             .iter()
             .map(|code| {
                 let result = solve(code, 2).unwrap();
-                // println!("first {}", result.len() - 1);
+                trace!(Debug, "{:?} takes {} presses", code.code, result.len() - 1);
                 code.value * (result.len() - 1) as isize
             })
             .sum())
@@ -497,18 +483,28 @@ impl Solver for Problem {
             .iter()
             .map(|code| {
                 let result = get_code_length(&code.code, 26, &mut cache);
-                // println!("second {}", result);
+                trace!(Debug, "{:?} takes {} presses", code.code, result);
                 code.value * result as isize
             })
             .sum())
-        // Ok(get_code_length(&input[0].code, 3, &mut cache))
-        // Ok(input
-        //     .iter()
-        //     .map(|code| {
-        //         println!("Do it");
-        //         let result = solve(code, 25).unwrap();
-        //         code.value * (result.len() - 1) as isize
-        //     })
-        //     .sum())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/21",
+            part1: Some("126384"),
+            part2: None,
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
     }
 }
@@ -1,9 +1,8 @@
 use itertools::Itertools;
 
-use super::Solver;
+use super::{parse_field, parse_lines, Example, Param, ParseError, Solver};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 pub struct Problem;
 
 fn next_secret(secret: isize) -> isize {
@@ -20,11 +19,11 @@ fn nth_secret(secret: isize, n: isize) -> isize {
 }
 
 type Sequence = (isize, isize, isize, isize);
-fn sequences(secret: isize) -> HashMap<Sequence, isize> {
+fn sequences(secret: isize, n: usize) -> HashMap<Sequence, isize> {
     let mut result = HashMap::new();
     let mut secrets = vec![secret];
     let mut secret = secret;
-    for _ in 0..2000 {
+    for _ in 0..n {
         secret = next_secret(secret);
         secrets.push(secret);
     }
@@ -63,20 +62,21 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|x| x.parse().unwrap())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| parse_field(line, line))
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
-        Ok(input.iter().map(|v| nth_secret(*v, 2000)).sum())
+        let iterations = self.param("iterations")?;
+        Ok(input.iter().map(|v| nth_secret(*v, iterations)).sum())
     }
 
     fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
-        let sequences = input.iter().map(|v| sequences(*v)).collect_vec();
+        let iterations = self.param("iterations")?;
+        let sequences = input
+            .iter()
+            .map(|v| sequences(*v, iterations))
+            .collect_vec();
         let joined = join_sequences(sequences);
 
         let winning = joined.iter().max_by(|(_, a), (_, b)| a.cmp(b)).unwrap();
@@ -84,4 +84,39 @@ impl Solver for Problem {
         // 9245
         Ok(*winning.1)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                file: "inputs/2024/examples/22",
+                part1: Some("37327623"),
+                part2: None,
+                params: &[],
+            },
+            Example {
+                file: "inputs/2024/examples/22-2",
+                part1: None,
+                part2: Some("23"),
+                params: &[],
+            },
+        ]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param {
+            name: "iterations",
+            default: "2000",
+            help: "secret numbers each buyer generates",
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
@@ -1,9 +1,8 @@
 use itertools::Itertools;
 
-use super::Solver;
+use super::{read_lines, Example, ParseError, Solver};
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::str::FromStr;
 
 pub struct Problem;
@@ -86,11 +85,14 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = String;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
         let mut result: Self::Input = HashMap::new();
-        for line in lines {
-            let (a, b) = line.split("-").collect_tuple().unwrap();
+        for (i, line) in lines.iter().enumerate() {
+            let (a, b) = line
+                .split("-")
+                .collect_tuple()
+                .ok_or_else(|| ParseError::new(line, 0, "expected `a-b`").line(i))?;
 
             result
                 .entry(String::from_str(a).unwrap())
@@ -114,7 +116,7 @@ impl Solver for Problem {
                 });
         }
 
-        result
+        Ok(result)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -143,4 +145,42 @@ impl Solver for Problem {
 
         Ok(max.join(","))
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/23",
+            part1: Some("7"),
+            part2: Some("co,de,ka,ta"),
+            params: &[],
+        }]
+    }
+
+    fn dumps(&self) -> Vec<&'static str> {
+        vec!["adjacency"]
+    }
+
+    fn dump(&self, input: &Self::Input, name: &str) -> Option<String> {
+        match name {
+            "adjacency" => Some(
+                input
+                    .iter()
+                    .sorted_by_key(|(computer, _)| computer.as_str())
+                    .map(|(computer, links)| {
+                        format!("{computer}: {}", links.iter().sorted().join(","))
+                    })
+                    .join("\n"),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
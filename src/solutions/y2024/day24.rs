@@ -1,10 +1,9 @@
 use itertools::Itertools;
 
-use super::Solver;
+use super::{parse_field, read_lines, trace, Example, ParseError, Solver};
 use core::panic;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::str::FromStr;
 
 pub struct Problem;
@@ -258,7 +257,7 @@ fn solve_position(
                     }
                 }
                 TestResult::Root => {
-                    println!("Find root");
+                    trace!(Debug, "Find root");
                     let (correct, value, carry) =
                         find_position(position, &prev_value, &prev_carry, &ops, &fixed).unwrap();
                     swap(&correct, &key, ops, fixed, swaps);
@@ -326,7 +325,6 @@ fn test_position(
                     carry: r.clone(),
                 };
             }
-            // println!("root => both!");
             return TestResult::Root;
         };
 
@@ -369,42 +367,49 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = String;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
         let mut result: HashMap<String, Operation> = HashMap::new();
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+        let lines = read_lines(file_reader)?;
         let mut mode = 0;
-        for l in lines {
+        for (i, l) in lines.iter().enumerate() {
             if l == "" {
                 mode = 1;
                 continue;
             }
             if mode == 0 {
-                let (k, v) = l.split(": ").collect_tuple().unwrap();
+                let (k, v) = l
+                    .split(": ")
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::new(l, 0, "expected `wire: value`").line(i))?;
                 result.insert(
                     String::from_str(k).unwrap(),
-                    Operation::Hard(v.parse().unwrap()),
+                    Operation::Hard(parse_field(l, v).map_err(|err| err.line(i))?),
                 );
             } else {
-                let (op, k) = l.split(" -> ").collect_tuple().unwrap();
-                let (kl, op, kr) = op.split(" ").collect_tuple().unwrap();
+                let (op, k) = l
+                    .split(" -> ")
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::new(l, 0, "expected `a OP b -> wire`").line(i))?;
+                let (kl, op, kr) = op
+                    .split(" ")
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::new(l, 0, "expected `a OP b`").line(i))?;
 
                 let (kl, kr) = (String::from_str(kl).unwrap(), String::from_str(kr).unwrap());
                 if result.contains_key(k) {
-                    panic!("Lol trick!");
+                    return Err(ParseError::at(l, k, format!("wire `{k}` is set twice")).line(i));
                 }
-                result.insert(
-                    String::from_str(k).unwrap(),
-                    match op {
-                        "XOR" => Operation::XOR(kl, kr),
-                        "OR" => Operation::OR(kl, kr),
-                        "AND" => Operation::AND(kl, kr),
-                        _ => panic!(),
-                    },
-                );
+                let operation = match op {
+                    "XOR" => Operation::XOR(kl, kr),
+                    "OR" => Operation::OR(kl, kr),
+                    "AND" => Operation::AND(kl, kr),
+                    _ => return Err(ParseError::at(l, op, format!("unknown gate `{op}`")).line(i)),
+                };
+                result.insert(String::from_str(k).unwrap(), operation);
             }
         }
 
-        result
+        Ok(result)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -423,10 +428,29 @@ impl Solver for Problem {
                 break;
             }
 
-            println!("{i}, {:?}", swaps.iter().sorted().join(","));
+            trace!(Debug, "{i}, {:?}", swaps.iter().sorted().join(","));
             prev = Some(solve_position(i, prev, &mut ops, &mut fixed, &mut swaps));
         }
 
         todo!()
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            file: "inputs/2024/examples/24",
+            part1: Some("4"),
+            part2: None,
+            params: &[],
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert!(Problem.solve_examples(3));
+    }
 }
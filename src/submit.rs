@@ -0,0 +1,292 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use regex::Regex;
+
+use crate::client::{self, Server};
+use crate::runner;
+use crate::solutions::{self, Answer, Params};
+use crate::verify;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Seconds left before another answer is accepted
+    Wait(u64),
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn parse(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Outcome::Correct
+        } else if body.contains("That's not the right answer") {
+            if body.contains("too high") {
+                Outcome::TooHigh
+            } else if body.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if body.contains("You gave an answer too recently") {
+            let regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let seconds = regex.captures(body).map_or(0, |res| {
+                let value = |i| res.get(i).map_or(0, |v| v.as_str().parse().unwrap_or(0));
+                value(1) * 60 + value(2)
+            });
+            Outcome::Wait(seconds)
+        } else if body.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    // Waiting or unrecognised responses say nothing about the answer, so they aren't stored
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::TooHigh => Some("too-high"),
+            Outcome::TooLow => Some("too-low"),
+            Outcome::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(seconds) => write!(f, "too soon, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+pub struct Submission {
+    pub part: usize,
+    pub outcome: Outcome,
+    pub answer: Answer,
+}
+
+pub fn submissions_file(year: usize, day: usize) -> String {
+    format!("submissions/{year}/{:02}", day)
+}
+
+// Stored one per line as `<part> <outcome>: <answer>`, like the answers files
+pub fn read_submissions(year: usize, day: usize) -> Vec<Submission> {
+    let Ok(contents) = std::fs::read_to_string(submissions_file(year, day)) else {
+        return vec![];
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (key, answer) = line.split_once(": ")?;
+            let (part, outcome) = key.split_once(' ')?;
+            Some(Submission {
+                part: part.parse().ok()?,
+                outcome: Outcome::from_key(outcome)?,
+                answer: Answer::from_line(answer),
+            })
+        })
+        .collect()
+}
+
+fn record(
+    year: usize,
+    day: usize,
+    part: usize,
+    outcome: Outcome,
+    answer: &Answer,
+) -> std::io::Result<()> {
+    let Some(key) = outcome.key() else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(format!("submissions/{year}"))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(submissions_file(year, day))?;
+    writeln!(file, "{part} {key}: {}", answer.to_line())
+}
+
+// Why `answer` shouldn't be submitted, judging by earlier submissions
+// Only integer answers can be judged by earlier too high or too low outcomes
+pub fn known_outcome(submissions: &[Submission], part: usize, answer: &Answer) -> Option<String> {
+    for submission in submissions.iter().filter(|s| s.part == part) {
+        match submission.outcome {
+            Outcome::Correct => {
+                return Some(format!("part already solved with {}", submission.answer))
+            }
+            outcome if submission.answer == *answer => {
+                return Some(format!("it was already submitted and was {outcome}"))
+            }
+            Outcome::TooHigh if *answer >= submission.answer => {
+                return Some(format!("{} was already too high", submission.answer))
+            }
+            Outcome::TooLow if *answer <= submission.answer => {
+                return Some(format!("{} was already too low", submission.answer))
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub async fn submit(
+    year: usize,
+    day: usize,
+    part: usize,
+    server: &Server,
+) -> Result<Outcome, Box<dyn Error>> {
+    if part != 1 && part != 2 {
+        return Err("part must be 1 or 2".into());
+    }
+    solutions::get_solver(year, day).ok_or("day not implemented")?;
+    let input = runner::read_input(&solutions::input_file(year, day))?;
+    // No time limit, the answer is worth waiting for
+    let results = runner::solve_parts(
+        year,
+        day,
+        input,
+        1 << (part - 1),
+        false,
+        None,
+        &Params::new(),
+    )
+    .map_err(|err| format!("{:#}", err.day(day)))?;
+    let result = results.into_iter().next().ok_or("no result")?;
+    let answer = result.answer.map_err(|err| format!("part {part} {err}"))?;
+
+    if let Some(reason) = known_outcome(&read_submissions(year, day), part, &answer) {
+        return Err(format!("Not submitting {answer}: {reason}").into());
+    }
+
+    println!(
+        "Submitting {answer} for {year} day {:02} part {}",
+        day, part
+    );
+    client::throttle().await?;
+    let response = client::build()?
+        .post(format!("{}/day/{day}/answer", server.base_url(year)))
+        .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+        .send()
+        .await?;
+    let body = client::body(response).await?;
+
+    let outcome = Outcome::parse(&body);
+    record(year, day, part, outcome, &answer)?;
+    if outcome == Outcome::Correct {
+        verify::store_answer(year, day, part, &answer)?;
+    }
+    println!("{outcome}");
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<main>\n<article><p>{message}</p></article>\n</main>")
+    }
+
+    #[test]
+    fn parse_outcomes() {
+        let outcomes = [
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> \
+                 closer to saving your vacation. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a>",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+                 you're using the full input data; there are also some general tips on the \
+                 <a href=\"/2024/about\">about page</a>. Please wait one minute before trying again. \
+                 <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.  If you're stuck, make sure \
+                 you're using the full input data. Please wait one minute before trying again. \
+                 <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+                Outcome::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full \
+                 input data. Please wait one minute before trying again. \
+                 <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+                Outcome::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 20s left to wait. \
+                 <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+                Outcome::Wait(80),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 42s left to wait. \
+                 <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+                Outcome::Wait(42),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it? \
+                 <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+                Outcome::AlreadySolved,
+            ),
+            ("Please log in to get your puzzle input.", Outcome::Unknown),
+        ];
+        for (message, outcome) in outcomes {
+            assert_eq!(Outcome::parse(&page(message)), outcome, "{message}");
+        }
+    }
+
+    fn submission(part: usize, outcome: Outcome, answer: &str) -> Submission {
+        Submission {
+            part,
+            outcome,
+            answer: Answer::from(answer),
+        }
+    }
+
+    #[test]
+    fn known_outcomes() {
+        let submissions = [
+            submission(1, Outcome::TooHigh, "100"),
+            submission(1, Outcome::TooLow, "10"),
+            submission(1, Outcome::Wrong, "42"),
+            submission(2, Outcome::Correct, "7"),
+        ];
+        let known = |part, answer: &str| known_outcome(&submissions, part, &Answer::from(answer));
+        assert!(known(1, "150").is_some());
+        assert!(known(1, "100").is_some());
+        assert!(known(1, "5").is_some());
+        assert!(known(1, "42").is_some());
+        assert!(known(1, "50").is_none());
+        // Text can't be compared with the earlier numbers
+        assert!(known(1, "abc").is_none());
+        assert!(known(2, "8").is_some());
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use itertools::Itertools;
+
+use crate::history;
+use crate::output::{self, Format, Record};
+use crate::runner;
+use crate::solutions::{self, get_elapsed, Answer, Params};
+
+pub fn answers_file(year: usize, day: usize) -> String {
+    format!("answers/{year}/{:02}", day)
+}
+
+// Answers are stored one per line as `<part>: <answer>`, lines of other parts are skipped
+pub fn read_answers(year: usize, day: usize) -> Option<HashMap<usize, Answer>> {
+    let contents = std::fs::read_to_string(answers_file(year, day)).ok()?;
+    Some(parse_answers(&contents))
+}
+
+fn parse_answers(contents: &str) -> HashMap<usize, Answer> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(": ")?;
+            let part = part.parse().ok().filter(|part| (1..=2).contains(part))?;
+            Some((part, Answer::from_line(answer)))
+        })
+        .collect()
+}
+
+pub fn store_answer(year: usize, day: usize, part: usize, answer: &Answer) -> std::io::Result<()> {
+    let mut answers = read_answers(year, day).unwrap_or_default();
+    answers.insert(part, answer.clone());
+    let contents = answers
+        .iter()
+        .sorted_by_key(|(part, _)| **part)
+        .map(|(part, answer)| format!("{part}: {}\n", answer.to_line()))
+        .collect::<String>();
+    std::fs::create_dir_all(format!("answers/{year}"))?;
+    std::fs::write(answers_file(year, day), contents)
+}
+
+pub fn verify(year: usize, days: Vec<usize>, format: Format, timeout: Option<Duration>) -> bool {
+    let (mut passed, mut changed, mut failed) = (0, 0, 0);
+    let mut records = vec![];
+
+    for day in days {
+        if solutions::get_solver(year, day).is_none() {
+            continue;
+        }
+        let Some(answers) = read_answers(year, day) else {
+            if format == Format::Text {
+                println!("Day {:02}: no stored answers", day);
+            }
+            continue;
+        };
+        let parts = answers.keys().fold(0, |acc, part| acc | 1 << (part - 1));
+        let results = runner::read_input(&solutions::input_file(year, day))
+            .map_err(|err| err.to_string())
+            .and_then(|input| {
+                runner::solve_parts(year, day, input, parts, false, timeout, &Params::new())
+                    .map_err(|err| format!("invalid input: {err}"))
+            });
+        let results = match results {
+            Ok(results) => results,
+            Err(err) => {
+                for (part, expected) in answers.iter() {
+                    records.push(Record {
+                        expected: Some(expected.clone()),
+                        status: Some("failed"),
+                        ..Record::failed(year, day, *part, &err)
+                    });
+                }
+                if format == Format::Text {
+                    println!("Day {:02}: {}", day, err);
+                }
+                failed += answers.len();
+                continue;
+            }
+        };
+
+        if let Err(err) = history::record(year, day, &results) {
+            eprintln!("Couldn't record timings: {err}");
+        }
+        for result in results {
+            let expected = &answers[&result.part];
+            let status = match &result.answer {
+                Ok(answer) if answer == expected => {
+                    passed += 1;
+                    if format == Format::Text {
+                        println!(
+                            "Day {:02} part {}: pass ({})",
+                            day,
+                            result.part,
+                            get_elapsed(result.elapsed)
+                        );
+                    }
+                    "pass"
+                }
+                Ok(answer) => {
+                    changed += 1;
+                    if format == Format::Text {
+                        println!(
+                            "Day {:02} part {}: changed, got {} but expected {}",
+                            day, result.part, answer, expected
+                        );
+                    }
+                    "changed"
+                }
+                Err(failure) => {
+                    failed += 1;
+                    if format == Format::Text {
+                        println!("Day {:02} part {}: failed, {}", day, result.part, failure);
+                    }
+                    "failed"
+                }
+            };
+            records.push(Record {
+                expected: Some(expected.clone()),
+                status: Some(status),
+                ..Record::new(year, day, &result)
+            });
+        }
+    }
+
+    match format {
+        Format::Text => println!("{passed} passed, {changed} changed, {failed} failed"),
+        Format::Json => output::print_json(&records),
+    }
+    changed == 0 && failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_of_other_parts_are_skipped() {
+        let answers = parse_answers("0: 5\n1: 42\n2: abc\n3: 7\nnot an answer\n");
+        assert_eq!(
+            answers,
+            HashMap::from([(1, Answer::Int(42)), (2, Answer::Text(String::from("abc")))])
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, SystemTime};
+
+use itertools::Itertools;
+use tokio::process::Command;
+
+use crate::solutions::trace::Target;
+use crate::solutions::{self, ParamValue};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// The day's solution, input, examples and every shared module. Other days are
+// left out so editing them doesn't trigger a run.
+fn watched_files(year: usize, day: usize) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/solutions/y{year}/day{:02}.rs", day)),
+        PathBuf::from(solutions::input_file(year, day)),
+        PathBuf::from(format!("inputs/{year}/examples/{:02}", day)),
+    ];
+    examples(year, day, &mut files);
+    shared_modules(Path::new("src"), &mut files);
+    files
+}
+
+// Extra examples are stored as `DD-2`, `DD-3`...
+fn examples(year: usize, day: usize, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(format!("inputs/{year}/examples")) else {
+        return;
+    };
+    let prefix = format!("{:02}-", day);
+    let mut extra = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .collect::<Vec<_>>();
+    // Directory order isn't stable, and the list is compared between polls
+    extra.sort();
+    files.extend(extra);
+}
+
+fn shared_modules(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        let is_year = name
+            .strip_prefix('y')
+            .is_some_and(|year| !year.is_empty() && year.chars().all(|c| c.is_ascii_digit()));
+        if path.is_dir() && !is_year {
+            shared_modules(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+// Missing files are part of the snapshot, so creating or deleting one counts as a change
+fn snapshot(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| {
+            std::fs::metadata(file)
+                .and_then(|meta| meta.modified())
+                .ok()
+        })
+        .collect()
+}
+
+async fn wait_for_change(year: usize, day: usize) {
+    let files = watched_files(year, day);
+    let before = snapshot(&files);
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        // A new shared module changes the list itself
+        let now = watched_files(year, day);
+        if now != files || snapshot(&now) != before {
+            // Editors often write a file in several steps, let them finish
+            tokio::time::sleep(POLL_INTERVAL).await;
+            return;
+        }
+    }
+}
+
+// Builds with the profile and features of the running binary
+async fn build() -> std::io::Result<bool> {
+    let mut command = Command::new("cargo");
+    command.arg("build");
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        command.args(["--features", "alloc-stats"]);
+    }
+    Ok(command.status().await?.success())
+}
+
+// Answer, or error, of each part of the rebuilt binary's JSON output
+fn read_answers(json: &[u8]) -> Result<HashMap<usize, String>, serde_json::Error> {
+    let records: Vec<serde_json::Value> = serde_json::from_slice(json)?;
+    Ok(records
+        .iter()
+        .filter_map(|record| {
+            let part = record["part"].as_u64()? as usize;
+            let answer = match (&record["answer"], &record["error"]) {
+                (serde_json::Value::String(answer), _) => answer.clone(),
+                (_, serde_json::Value::String(error)) => error.clone(),
+                _ => return None,
+            };
+            Some((part, answer))
+        })
+        .collect())
+}
+
+// Rebuilds and runs `day` with its examples every time its files change, the
+// answers are compared to the ones of the previous run
+pub async fn watch(
+    year: usize,
+    day: usize,
+    parts: usize,
+    timeout: u64,
+    params: &[ParamValue],
+    trace: &[Target],
+    trace_file: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let exe = std::env::current_exe()?;
+    let mut last: Option<HashMap<usize, String>> = None;
+    loop {
+        println!(
+            "=== {} day {:02} at {}",
+            year,
+            day,
+            chrono::Local::now().format("%H:%M:%S")
+        );
+        if !build().await? {
+            println!("Build failed, waiting for changes");
+            wait_for_change(year, day).await;
+            continue;
+        }
+
+        let mut args = vec![
+            "--year".to_string(),
+            year.to_string(),
+            "run".to_string(),
+            day.to_string(),
+            "--part".to_string(),
+            parts.to_string(),
+            "--timeout".to_string(),
+            timeout.to_string(),
+        ];
+        for param in params {
+            args.push("--param".to_string());
+            args.push(format!("{}={}", param.name, param.value));
+        }
+        for target in trace {
+            args.push("--trace".to_string());
+            args.push(target.to_string());
+        }
+        if let Some(path) = trace_file {
+            // Both runs append to it, so it holds the traces of the latest build
+            std::fs::File::create(path)?;
+            args.push("--trace-file".to_string());
+            args.push(path.to_string());
+        }
+        Command::new(&exe)
+            .args(&args)
+            .arg("--example")
+            .status()
+            .await?;
+        // Traces and errors go straight to the terminal, stdout is the JSON
+        let output = Command::new(&exe)
+            .args(&args)
+            .args(["--format", "json"])
+            .stderr(Stdio::inherit())
+            .output()
+            .await?;
+        match read_answers(&output.stdout) {
+            Ok(answers) => {
+                for (part, answer) in answers.iter().sorted() {
+                    let previous = last.as_ref().and_then(|last| last.get(part));
+                    match previous {
+                        Some(previous) if previous == answer => {
+                            println!("Part {part}: {answer} (unchanged)")
+                        }
+                        Some(previous) => println!("Part {part}: {answer} (was {previous})"),
+                        None => println!("Part {part}: {answer}"),
+                    }
+                }
+                last = Some(answers);
+            }
+            Err(_) => println!("No answers, see the errors above"),
+        }
+
+        wait_for_change(year, day).await;
+    }
+}